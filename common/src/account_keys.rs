@@ -0,0 +1,76 @@
+use solana_program::instruction::CompiledInstruction;
+use solana_program::pubkey::Pubkey;
+use solana_transaction_status::TransactionWithStatusMeta;
+
+/// Account keys of a transaction in the order instructions index them: the static keys of the
+/// message, then the writable and the readonly addresses a v0 transaction loads from address
+/// lookup tables, as reported in `meta.loaded_addresses`.
+#[derive(Clone, Copy, Debug)]
+pub struct AccountKeys<'a> {
+    static_keys: &'a [Pubkey],
+    loaded_writable: &'a [Pubkey],
+    loaded_readonly: &'a [Pubkey],
+}
+
+impl<'a> AccountKeys<'a> {
+    pub fn new(
+        static_keys: &'a [Pubkey],
+        loaded_writable: &'a [Pubkey],
+        loaded_readonly: &'a [Pubkey],
+    ) -> Self {
+        AccountKeys {
+            static_keys,
+            loaded_writable,
+            loaded_readonly,
+        }
+    }
+    /// A legacy transaction or a transaction without status meta has only static keys
+    pub fn from_transaction(tran: &'a TransactionWithStatusMeta) -> Self {
+        let loaded_addresses = tran.meta.as_ref().map(|meta| &meta.loaded_addresses);
+        AccountKeys::new(
+            tran.transaction.message.account_keys.as_slice(),
+            loaded_addresses
+                .map(|addresses| addresses.writable.as_slice())
+                .unwrap_or_default(),
+            loaded_addresses
+                .map(|addresses| addresses.readonly.as_slice())
+                .unwrap_or_default(),
+        )
+    }
+    pub fn len(&self) -> usize {
+        self.static_keys.len() + self.loaded_writable.len() + self.loaded_readonly.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, index: usize) -> Option<&'a Pubkey> {
+        let mut index = index;
+        for keys in [self.static_keys, self.loaded_writable, self.loaded_readonly] {
+            if index < keys.len() {
+                return keys.get(index);
+            }
+            index -= keys.len();
+        }
+        None
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &'a Pubkey> {
+        self.static_keys
+            .iter()
+            .chain(self.loaded_writable.iter())
+            .chain(self.loaded_readonly.iter())
+    }
+    pub fn program_id(&self, instruction: &CompiledInstruction) -> Option<&'a Pubkey> {
+        self.get(instruction.program_id_index as usize)
+    }
+    /// Accounts of an instruction in order, or the first account index that cannot be resolved
+    pub fn instruction_accounts(
+        &self,
+        instruction: &CompiledInstruction,
+    ) -> Result<Vec<Pubkey>, u8> {
+        instruction
+            .accounts
+            .iter()
+            .map(|index| self.get(*index as usize).copied().ok_or(*index))
+            .collect()
+    }
+}
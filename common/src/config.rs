@@ -0,0 +1,230 @@
+use lazy_static::lazy_static;
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+lazy_static! {
+    pub static ref FAILED_TRANSACTION_POLICY: FailedTransactionPolicy =
+        FailedTransactionPolicy::from_env();
+    pub static ref ERROR_POLICY: ErrorPolicy = ErrorPolicy::from_env();
+    pub static ref FLUSH_CONFIG: FlushConfig = FlushConfig::from_env();
+    pub static ref DECODE_CONFIG: DecodeConfig = DecodeConfig::from_env();
+    pub static ref ENRICHMENT_CONFIG: EnrichmentConfig = EnrichmentConfig::from_env();
+    pub static ref LOCAL_STATE_CONFIG: LocalStateConfig = LocalStateConfig::from_env();
+}
+
+/// Decide which transactions are indexed depending on their status.
+/// Set with the `FAILED_TRANSACTION_POLICY` env: `skip` (default), `include` or `only_failed`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FailedTransactionPolicy {
+    /// Index only successful transactions
+    Skip,
+    /// Index all transactions, failed ones are flagged with `success` and `error`
+    Include,
+    /// Index only failed transactions, for debugging
+    OnlyFailed,
+}
+
+impl FailedTransactionPolicy {
+    pub fn from_env() -> Self {
+        match env::var("FAILED_TRANSACTION_POLICY") {
+            Ok(value) => FailedTransactionPolicy::parse(value.as_str()).unwrap_or_else(|| {
+                println!(
+                    "Unknown FAILED_TRANSACTION_POLICY {}, fallback to skip",
+                    value
+                );
+                FailedTransactionPolicy::Skip
+            }),
+            Err(_) => FailedTransactionPolicy::Skip,
+        }
+    }
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "skip" => Some(FailedTransactionPolicy::Skip),
+            "include" => Some(FailedTransactionPolicy::Include),
+            "only_failed" => Some(FailedTransactionPolicy::OnlyFailed),
+            _ => None,
+        }
+    }
+    pub fn accept(&self, success: bool) -> bool {
+        match self {
+            FailedTransactionPolicy::Skip => success,
+            FailedTransactionPolicy::Include => true,
+            FailedTransactionPolicy::OnlyFailed => !success,
+        }
+    }
+}
+
+/// What to do when an instruction cannot be decoded.
+/// Set with the `ERROR_POLICY` env: `continue` (default) or `fail_fast`.
+/// Store and RPC errors always fail the block, so the host can retry it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorPolicy {
+    /// Stop and return the error, the block is not committed. A retry fails the same way, so
+    /// the indexer stays on a block the parser cannot decode: use it to debug a parser.
+    FailFast,
+    /// Save the error as an `IndexingError` entity and continue with the next instruction
+    Continue,
+}
+
+impl ErrorPolicy {
+    pub fn from_env() -> Self {
+        match env::var("ERROR_POLICY") {
+            Ok(value) => ErrorPolicy::parse(value.as_str()).unwrap_or_else(|| {
+                println!("Unknown ERROR_POLICY {}, fallback to continue", value);
+                ErrorPolicy::Continue
+            }),
+            Err(_) => ErrorPolicy::Continue,
+        }
+    }
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "fail_fast" => Some(ErrorPolicy::FailFast),
+            "continue" => Some(ErrorPolicy::Continue),
+            _ => None,
+        }
+    }
+}
+
+/// When the store is flushed while handling an array of blocks.
+/// The store is flushed once `max_batch_size` blocks are pending or `max_delay` has passed
+/// since the last flush, and always after the last block of the array.
+/// Set with the `FLUSH_MAX_BATCH_SIZE` and `FLUSH_MAX_DELAY_MS` envs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlushConfig {
+    pub max_batch_size: usize,
+    pub max_delay: Duration,
+}
+
+impl Default for FlushConfig {
+    fn default() -> Self {
+        FlushConfig {
+            max_batch_size: 100,
+            max_delay: Duration::from_millis(5000),
+        }
+    }
+}
+
+impl FlushConfig {
+    pub fn from_env() -> Self {
+        let default = FlushConfig::default();
+        FlushConfig {
+            max_batch_size: env::var("FLUSH_MAX_BATCH_SIZE")
+                .ok()
+                .and_then(|value| value.parse::<usize>().ok())
+                .map(|value| value.max(1))
+                .unwrap_or(default.max_batch_size),
+            max_delay: env::var("FLUSH_MAX_DELAY_MS")
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(default.max_delay),
+        }
+    }
+}
+
+/// Number of threads decoding the transactions of a block, entities are still written in
+/// transaction order. Set with the `DECODE_WORKERS` env, `1` (default) decodes serially and
+/// `0` uses one thread per CPU. Transactions are only decoded in parallel when every parser
+/// was injected as `Sync` with `inject_sync_interface`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecodeConfig {
+    pub workers: usize,
+}
+
+impl Default for DecodeConfig {
+    fn default() -> Self {
+        DecodeConfig { workers: 1 }
+    }
+}
+
+impl DecodeConfig {
+    pub fn from_env() -> Self {
+        let default = DecodeConfig::default();
+        DecodeConfig {
+            workers: env::var("DECODE_WORKERS")
+                .ok()
+                .and_then(|value| value.parse::<usize>().ok())
+                .unwrap_or(default.workers),
+        }
+    }
+}
+
+/// Optional stage fetching the accounts referenced in a block before decoding.
+/// Set with the `ENRICH_ACCOUNTS` env (`true` to enable) and `ACCOUNT_CACHE_SIZE`,
+/// the number of immutable accounts kept in the LRU cache.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnrichmentConfig {
+    pub enabled: bool,
+    pub cache_size: usize,
+}
+
+impl Default for EnrichmentConfig {
+    fn default() -> Self {
+        EnrichmentConfig {
+            enabled: false,
+            cache_size: 10000,
+        }
+    }
+}
+
+impl EnrichmentConfig {
+    pub fn from_env() -> Self {
+        let default = EnrichmentConfig::default();
+        EnrichmentConfig {
+            enabled: env::var("ENRICH_ACCOUNTS")
+                .map(|value| matches!(value.to_lowercase().as_str(), "true" | "1"))
+                .unwrap_or(default.enabled),
+            cache_size: env::var("ACCOUNT_CACHE_SIZE")
+                .ok()
+                .and_then(|value| value.parse::<usize>().ok())
+                .map(|value| value.max(1))
+                .unwrap_or(default.cache_size),
+        }
+    }
+}
+
+/// On-disk database of the facts learnt from the indexed blocks that the store cannot be queried
+/// for, kept across restarts. Each plugin opens its own directory under the `LOCAL_STATE_PATH`
+/// env (default `local-state`), and keeps up to `LOCAL_STATE_CACHE_MB` megabytes of it in memory
+/// (default 64).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalStateConfig {
+    pub path: PathBuf,
+    pub cache_size: u64,
+}
+
+impl Default for LocalStateConfig {
+    fn default() -> Self {
+        LocalStateConfig {
+            path: PathBuf::from("local-state"),
+            cache_size: 64 * 1024 * 1024,
+        }
+    }
+}
+
+impl LocalStateConfig {
+    pub fn from_env() -> Self {
+        let default = LocalStateConfig::default();
+        LocalStateConfig {
+            path: env::var("LOCAL_STATE_PATH")
+                .map(PathBuf::from)
+                .unwrap_or(default.path),
+            cache_size: env::var("LOCAL_STATE_CACHE_MB")
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(|value| value * 1024 * 1024)
+                .unwrap_or(default.cache_size),
+        }
+    }
+}
+
+/// Public RPC endpoint of a cluster, mainnet is used for unknown networks
+pub fn default_rpc_url(network: &str) -> &'static str {
+    match network {
+        "devnet" => "https://api.devnet.solana.com",
+        "testnet" => "https://api.testnet.solana.com",
+        "localnet" | "localhost" => "http://127.0.0.1:8899",
+        _ => "https://api.mainnet-beta.solana.com",
+    }
+}
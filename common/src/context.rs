@@ -0,0 +1,133 @@
+use crate::error::IndexerError;
+use massbit_solana_sdk::smart_contract::InstructionParser;
+use massbit_solana_sdk::store::IndexStore;
+use massbit_solana_sdk::transport::TransportValue;
+use solana_program::pubkey::Pubkey;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// Store and instruction parsers injected by the host, parsers are keyed by program id.
+/// Access goes through a mutex, a missing store or parser is reported as an `IndexerError`.
+#[derive(Default)]
+pub struct PluginContext {
+    store: Mutex<Option<StoreRef>>,
+    interfaces: Mutex<HashMap<Pubkey, InterfaceRef>>,
+}
+
+struct StoreRef(&'static mut dyn IndexStore);
+
+enum InterfaceRef {
+    /// Parser of unknown thread safety, only used by the thread handling the blocks
+    Exclusive(&'static mut dyn InstructionParser),
+    /// Parser the host declared `Sync`, it can be shared by the decode workers
+    Shared(&'static (dyn InstructionParser + Sync)),
+}
+
+// The host keeps the store and the parser alive while the plugin is loaded,
+// and the references are only used while the context mutexes are held.
+unsafe impl Send for StoreRef {}
+unsafe impl Send for InterfaceRef {}
+
+/// Parsers of the indexed programs, keyed by program id
+pub enum Parsers<'a> {
+    /// Every parser is `Sync`, transactions can be decoded by the worker pool
+    Shared(HashMap<Pubkey, &'a (dyn InstructionParser + Sync)>),
+    /// A parser is not known to be thread safe, transactions are decoded on the calling thread
+    Exclusive(HashMap<Pubkey, &'a dyn InstructionParser>),
+}
+
+impl<'a> Parsers<'a> {
+    pub fn get(&self, program_id: &Pubkey) -> Option<&'a dyn InstructionParser> {
+        match self {
+            Parsers::Shared(parsers) => parsers
+                .get(program_id)
+                .map(|parser| *parser as &dyn InstructionParser),
+            Parsers::Exclusive(parsers) => parsers.get(program_id).copied(),
+        }
+    }
+}
+
+/// Entities built while decoding one transaction, written to the store afterwards in the
+/// order they were saved
+#[derive(Default)]
+pub struct EntityBatch {
+    values: RefCell<Vec<TransportValue>>,
+}
+
+impl EntityBatch {
+    pub fn push(&self, value: TransportValue) {
+        self.values.borrow_mut().push(value);
+    }
+    pub fn into_values(self) -> Vec<TransportValue> {
+        self.values.into_inner()
+    }
+}
+
+impl PluginContext {
+    pub fn set_store(&self, store: &'static mut dyn IndexStore) {
+        *lock(&self.store) = Some(StoreRef(store));
+    }
+    pub fn set_interface(&self, program_id: Pubkey, interface: &'static mut dyn InstructionParser) {
+        lock(&self.interfaces).insert(program_id, InterfaceRef::Exclusive(interface));
+    }
+    pub fn set_sync_interface(
+        &self,
+        program_id: Pubkey,
+        interface: &'static (dyn InstructionParser + Sync),
+    ) {
+        lock(&self.interfaces).insert(program_id, InterfaceRef::Shared(interface));
+    }
+    pub fn with_store<R>(
+        &self,
+        f: impl FnOnce(&mut dyn IndexStore) -> R,
+    ) -> Result<R, IndexerError> {
+        match lock(&self.store).as_mut() {
+            Some(store) => Ok(f(&mut *store.0)),
+            None => Err(IndexerError::MissingStore),
+        }
+    }
+    /// Run `f` with the parsers of all programs, they are shared by the decode workers only
+    /// when all of them were injected as `Sync`
+    pub fn with_parsers<R>(&self, f: impl FnOnce(&Parsers) -> R) -> R {
+        let interfaces = lock(&self.interfaces);
+        let shared = interfaces
+            .iter()
+            .map(|(program_id, interface)| match interface {
+                InterfaceRef::Shared(parser) => Some((*program_id, *parser)),
+                InterfaceRef::Exclusive(_) => None,
+            })
+            .collect::<Option<HashMap<Pubkey, &(dyn InstructionParser + Sync)>>>();
+        let parsers = match shared {
+            Some(parsers) => Parsers::Shared(parsers),
+            None => Parsers::Exclusive(
+                interfaces
+                    .iter()
+                    .map(|(program_id, interface)| {
+                        let parser: &dyn InstructionParser = match interface {
+                            InterfaceRef::Shared(parser) => *parser,
+                            InterfaceRef::Exclusive(parser) => &**parser,
+                        };
+                        (*program_id, parser)
+                    })
+                    .collect(),
+            ),
+        };
+        f(&parsers)
+    }
+    /// Save decoded entities in order, within a single lock of the store
+    pub fn write(&self, values: Vec<TransportValue>) -> Result<(), IndexerError> {
+        self.with_store(|store| {
+            for value in values.iter() {
+                store.save_values(&value.name, &value.values);
+            }
+        })
+    }
+}
+
+/// A panic while holding the lock does not invalidate the injected references
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
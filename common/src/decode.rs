@@ -0,0 +1,319 @@
+use crate::account_keys::AccountKeys;
+use crate::config::{ErrorPolicy, ERROR_POLICY, FAILED_TRANSACTION_POLICY};
+use crate::context::{EntityBatch, Parsers};
+use crate::enrichment::AccountStates;
+use crate::entity::{error_value, primary_signature, transaction_value, TransportValueExt};
+use crate::error::IndexerError;
+use crate::position::InstructionPosition;
+use massbit_solana_sdk::smart_contract::InstructionParser;
+use massbit_solana_sdk::transport::TransportValue;
+use massbit_solana_sdk::types::SolanaBlock;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+use solana_program::instruction::CompiledInstruction;
+use solana_program::pubkey::Pubkey;
+use solana_transaction_status::TransactionWithStatusMeta;
+use std::collections::{HashMap, HashSet};
+
+/// Entities of each indexed transaction of a block, in transaction order
+pub type DecodedBlock = Vec<Result<Vec<TransportValue>, IndexerError>>;
+
+/// Plugin side of the block decoding: which programs are indexed and how their decoded
+/// instructions are saved
+pub trait InstructionHandler: Sync {
+    /// True when the instructions of `program_id` are indexed in the block at `slot`
+    fn is_indexed(&self, program_id: &Pubkey, slot: u64) -> bool;
+    /// Save the entities of an instruction decoded by the parser of `program_id`
+    fn process(
+        &self,
+        context: &TransactionContext,
+        position: &InstructionPosition,
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: TransportValue,
+    ) -> Result<(), IndexerError>;
+}
+
+/// Transaction being decoded, with the batch its entities are saved to
+pub struct TransactionContext<'a> {
+    pub batch: &'a EntityBatch,
+    /// State of the accounts referenced in the block, empty unless enrichment is enabled
+    pub accounts: &'a AccountStates,
+    pub block: &'a SolanaBlock,
+    pub transaction: &'a TransactionWithStatusMeta,
+}
+
+/// Indexed programs whose parser is injected, instructions of the other programs are skipped
+/// rather than failing the block, so a host injecting a single parser still indexes its program
+struct Injected<'a, H> {
+    handler: &'a H,
+    program_ids: HashSet<Pubkey>,
+}
+
+impl<'a, H: InstructionHandler> Injected<'a, H> {
+    fn new(handler: &'a H, parsers: &Parsers) -> Self {
+        let program_ids = match parsers {
+            Parsers::Shared(parsers) => parsers.keys().copied().collect(),
+            Parsers::Exclusive(parsers) => parsers.keys().copied().collect(),
+        };
+        Injected {
+            handler,
+            program_ids,
+        }
+    }
+}
+
+impl<'a, H: InstructionHandler> InstructionHandler for Injected<'a, H> {
+    fn is_indexed(&self, program_id: &Pubkey, slot: u64) -> bool {
+        self.program_ids.contains(program_id) && self.handler.is_indexed(program_id, slot)
+    }
+    fn process(
+        &self,
+        context: &TransactionContext,
+        position: &InstructionPosition,
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: TransportValue,
+    ) -> Result<(), IndexerError> {
+        self.handler
+            .process(context, position, program_id, accounts, input)
+    }
+}
+
+/// Decode the indexed transactions of a block into the entities of each transaction.
+/// Transactions are decoded on `pool` when all parsers are `Sync`, otherwise on the calling
+/// thread. Results are in transaction order, so the store receives the same entities in the
+/// same order whatever the number of workers. Programs without an injected parser are skipped.
+pub fn decode_block<H: InstructionHandler>(
+    handler: &H,
+    parsers: &Parsers,
+    accounts: &AccountStates,
+    block: &SolanaBlock,
+    pool: &ThreadPool,
+) -> DecodedBlock {
+    let handler = &Injected::new(handler, parsers);
+    match parsers {
+        Parsers::Shared(parsers) if pool.current_num_threads() > 1 => pool.install(|| {
+            block
+                .block
+                .transactions
+                .par_iter()
+                .enumerate()
+                .filter(|(_, tran)| is_indexed(handler, block, tran))
+                .map(|(tx_ind, tran)| {
+                    decode_transaction(handler, parsers, accounts, block, tran, tx_ind)
+                })
+                .collect()
+        }),
+        Parsers::Shared(parsers) => decode_serial(handler, parsers, accounts, block),
+        Parsers::Exclusive(parsers) => decode_serial(handler, parsers, accounts, block),
+    }
+}
+
+fn decode_serial<H: InstructionHandler, P: InstructionParser + ?Sized>(
+    handler: &H,
+    parsers: &HashMap<Pubkey, &P>,
+    accounts: &AccountStates,
+    block: &SolanaBlock,
+) -> DecodedBlock {
+    block
+        .block
+        .transactions
+        .iter()
+        .enumerate()
+        .filter(|(_, tran)| is_indexed(handler, block, tran))
+        .map(|(tx_ind, tran)| decode_transaction(handler, parsers, accounts, block, tran, tx_ind))
+        .collect()
+}
+
+/// True when a program indexed at `slot` is one of the account keys, including loaded addresses
+pub fn involves_program<H: InstructionHandler>(
+    handler: &H,
+    tran: &TransactionWithStatusMeta,
+    slot: u64,
+) -> bool {
+    AccountKeys::from_transaction(tran)
+        .iter()
+        .any(|key| handler.is_indexed(key, slot))
+}
+
+/// Transactions of the indexed programs accepted by the failed transaction policy
+pub fn is_indexed<H: InstructionHandler>(
+    handler: &H,
+    block: &SolanaBlock,
+    tran: &TransactionWithStatusMeta,
+) -> bool {
+    involves_program(handler, tran, block.block_number)
+        && FAILED_TRANSACTION_POLICY.accept(is_success(tran))
+}
+
+/// Accounts of the indexed instructions of a block, top-level and inner. Programs without an
+/// injected parser are skipped like in `decode_block`.
+pub fn referenced_accounts<H: InstructionHandler>(
+    handler: &H,
+    parsers: &Parsers,
+    block: &SolanaBlock,
+) -> HashSet<Pubkey> {
+    let handler = &Injected::new(handler, parsers);
+    let mut accounts = HashSet::new();
+    for tran in block
+        .block
+        .transactions
+        .iter()
+        .filter(|tran| is_indexed(handler, block, tran))
+    {
+        let account_keys = AccountKeys::from_transaction(tran);
+        let inner_instructions = tran
+            .meta
+            .iter()
+            .flat_map(|meta| meta.inner_instructions.iter().flatten())
+            .flat_map(|inner_inst| inner_inst.instructions.iter());
+        for instruction in tran
+            .transaction
+            .message
+            .instructions
+            .iter()
+            .chain(inner_instructions)
+        {
+            let indexed = account_keys
+                .program_id(instruction)
+                .map_or(false, |key| handler.is_indexed(key, block.block_number));
+            if indexed {
+                // An unresolved account index is reported when the instruction is decoded
+                accounts.extend(
+                    account_keys
+                        .instruction_accounts(instruction)
+                        .unwrap_or_default(),
+                );
+            }
+        }
+    }
+    accounts
+}
+
+/// A transaction without status meta is considered successful
+pub fn is_success(tran: &TransactionWithStatusMeta) -> bool {
+    tran.meta
+        .as_ref()
+        .map(|meta| meta.status.is_ok())
+        .unwrap_or(true)
+}
+
+/// Decode the instructions of a transaction into the entities to save, without touching the store
+fn decode_transaction<H: InstructionHandler, P: InstructionParser + ?Sized>(
+    handler: &H,
+    parsers: &HashMap<Pubkey, &P>,
+    accounts: &AccountStates,
+    block: &SolanaBlock,
+    tran: &TransactionWithStatusMeta,
+    tx_ind: usize,
+) -> Result<Vec<TransportValue>, IndexerError> {
+    // Instructions are often invoked through CPI, so walk the inner instructions as well
+    let map_inner_instructions = tran
+        .meta
+        .as_ref()
+        .and_then(|trans_meta| {
+            trans_meta.inner_instructions.as_ref().map(|insts| {
+                insts
+                    .iter()
+                    .map(|inner_inst| (inner_inst.index, &inner_inst.instructions))
+                    .collect::<HashMap<u8, &Vec<CompiledInstruction>>>()
+            })
+        })
+        .unwrap_or_default();
+    let account_keys = AccountKeys::from_transaction(tran);
+    let batch = EntityBatch::default();
+    let context = TransactionContext {
+        batch: &batch,
+        accounts,
+        block,
+        transaction: tran,
+    };
+    let mut instruction_count = 0_u64;
+    for (ind, inst) in tran.transaction.message.instructions.iter().enumerate() {
+        let position = InstructionPosition::top_level(tx_ind, ind);
+        if process_instruction(handler, parsers, &context, &account_keys, &position, inst)? {
+            instruction_count += 1;
+        }
+        let inner_key = ind as u8;
+        if let Some(inner_instructions) = map_inner_instructions.get(&inner_key) {
+            for (inner_ind, inner_instruction) in inner_instructions.iter().enumerate() {
+                let position = InstructionPosition::inner(tx_ind, ind, inner_ind);
+                let parsed = process_instruction(
+                    handler,
+                    parsers,
+                    &context,
+                    &account_keys,
+                    &position,
+                    inner_instruction,
+                )?;
+                if parsed {
+                    instruction_count += 1;
+                }
+            }
+        }
+    }
+    let input = transaction_value(block, tran, tx_ind, instruction_count);
+    input.save(&batch)?;
+    println!("Write to db {:?}", input);
+    Ok(batch.into_values())
+}
+
+/// Decode an instruction of an indexed program with the parser of its program and pass it to
+/// the handler, returns false when the instruction is not indexed
+fn process_instruction<H: InstructionHandler, P: InstructionParser + ?Sized>(
+    handler: &H,
+    parsers: &HashMap<Pubkey, &P>,
+    context: &TransactionContext,
+    account_keys: &AccountKeys,
+    position: &InstructionPosition,
+    instruction: &CompiledInstruction,
+) -> Result<bool, IndexerError> {
+    // In a v0 transaction the program and the accounts may come from address lookup tables
+    let program_key = match account_keys.program_id(instruction) {
+        Some(program_key) if handler.is_indexed(program_key, context.block.block_number) => {
+            program_key
+        }
+        _ => return Ok(false),
+    };
+    let parser = match parsers.get(program_key) {
+        Some(parser) => parser,
+        None => return Ok(false),
+    };
+    println!("Start unpack_instruction, inst {:?}", &instruction);
+    let decoded = match account_keys.instruction_accounts(instruction) {
+        Ok(accounts) => parser
+            .unpack_instruction(instruction.data.as_slice())
+            .map(|trans_value| (accounts, trans_value))
+            .map_err(|e| e.to_string()),
+        Err(index) => Err(format!(
+            "Account index {} is out of the {} transaction accounts",
+            index,
+            account_keys.len()
+        )),
+    };
+    match decoded {
+        Ok((accounts, trans_value)) => {
+            println!("unpack_instruction Ok, trans_value: {:?}", &trans_value);
+            handler.process(context, position, program_key, &accounts, trans_value)?;
+        }
+        Err(message) => {
+            println!("Error unpack_instruction: {}", message);
+            let error = IndexerError::Decode {
+                tx_hash: primary_signature(context.transaction).unwrap_or_default(),
+                instruction_index: position.instruction_index,
+                inner_index: position.inner_index,
+                message,
+            };
+            match *ERROR_POLICY {
+                ErrorPolicy::FailFast => return Err(error),
+                ErrorPolicy::Continue => {
+                    let input = error_value(context.block, context.transaction, position, &error);
+                    input.save(context.batch)?;
+                    println!("Write to db {:?}", input);
+                }
+            }
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,97 @@
+use crate::error::IndexerError;
+use lazy_static::lazy_static;
+use lru::LruCache;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_request::MAX_MULTIPLE_ACCOUNTS;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+use solana_sdk::account::Account;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+lazy_static! {
+    static ref SPL_TOKEN_ID: Pubkey =
+        Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
+}
+
+/// Size of the data of an spl-token mint account
+const MINT_LEN: usize = 82;
+
+/// State of an account as returned by the RPC node
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountState {
+    pub account: Account,
+    /// Slot of the RPC response context, the state may be more recent than the indexed block
+    pub slot: u64,
+}
+
+/// State of the accounts referenced in a block, accounts that do not exist are left out
+pub type AccountStates = HashMap<Pubkey, AccountState>;
+
+/// Fetch the accounts referenced in a block with batched `getMultipleAccounts` calls.
+/// The state is the one returned by the RPC node at fetch time, not at the slot of the block.
+/// Metadata and edition accounts change with the instructions, so they are fetched again for
+/// every block. Programs, sysvars and mints are referenced by most instructions and the indexer
+/// only relies on what never changes in them, so they are kept in a bounded LRU cache.
+pub struct Enrichment {
+    client: Arc<RpcClient>,
+    immutable: Mutex<LruCache<Pubkey, AccountState>>,
+}
+
+impl Enrichment {
+    pub fn new(client: Arc<RpcClient>, cache_size: usize) -> Self {
+        Enrichment {
+            client,
+            immutable: Mutex::new(LruCache::new(cache_size.max(1))),
+        }
+    }
+    /// Return the state of the given accounts, only the accounts missing from the cache are
+    /// fetched, `MAX_MULTIPLE_ACCOUNTS` per call
+    pub fn prefetch(&self, keys: &HashSet<Pubkey>) -> Result<AccountStates, IndexerError> {
+        let mut immutable = self
+            .immutable
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut states = AccountStates::new();
+        let mut missing = Vec::new();
+        for key in keys {
+            match immutable.get(key) {
+                Some(state) => {
+                    states.insert(*key, state.clone());
+                }
+                None => missing.push(*key),
+            }
+        }
+        for chunk in missing.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let response = self
+                .client
+                .get_multiple_accounts_with_commitment(chunk, self.client.commitment())
+                .map_err(|err| IndexerError::Rpc(err.to_string()))?;
+            let slot = response.context.slot;
+            for (key, account) in chunk.iter().zip(response.value) {
+                if let Some(account) = account {
+                    let state = AccountState { account, slot };
+                    if is_immutable(&state.account) {
+                        immutable.put(*key, state.clone());
+                    }
+                    states.insert(*key, state);
+                }
+            }
+        }
+        println!(
+            "Enrichment: {} accounts, {} fetched",
+            keys.len(),
+            missing.len()
+        );
+        Ok(states)
+    }
+}
+
+/// Executable programs, sysvars and spl-token mints. A cached sysvar or mint keeps the data it
+/// was fetched with, the indexer does not read their changing fields (clock, supply).
+fn is_immutable(account: &Account) -> bool {
+    account.executable
+        || account.owner == sysvar::id()
+        || (account.owner == *SPL_TOKEN_ID && account.data.len() == MINT_LEN)
+}
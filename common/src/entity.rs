@@ -0,0 +1,199 @@
+use crate::context::EntityBatch;
+use crate::error::IndexerError;
+use crate::position::InstructionPosition;
+use massbit_solana_sdk::{
+    transport::{TransportValue, Value},
+    types::SolanaBlock,
+};
+use solana_transaction_status::TransactionWithStatusMeta;
+
+pub trait TransportValueExt {
+    fn save(&self, batch: &EntityBatch) -> Result<(), IndexerError>;
+}
+impl TransportValueExt for TransportValue {
+    /// Entities are buffered in the batch of their transaction, the store is written after decoding
+    fn save(&self, batch: &EntityBatch) -> Result<(), IndexerError> {
+        batch.push(self.clone());
+        Ok(())
+    }
+}
+
+/// The first signature identifies the transaction on explorers
+pub fn primary_signature(transaction: &TransactionWithStatusMeta) -> Option<String> {
+    transaction
+        .transaction
+        .signatures
+        .get(0)
+        .map(|sig| sig.to_string())
+}
+
+/// Sum the compute units consumed by the top-level invocations in the log messages
+pub fn compute_units_consumed(log_messages: &[String]) -> u64 {
+    let mut depth = 0;
+    let mut consumed = 0;
+    for log in log_messages {
+        let words = log.split_whitespace().collect::<Vec<&str>>();
+        match words.as_slice() {
+            ["Program", _, "invoke", level] => {
+                depth = level
+                    .trim_matches(|c| c == '[' || c == ']')
+                    .parse::<u32>()
+                    .unwrap_or(depth + 1);
+            }
+            ["Program", _, "success"] | ["Program", _, "failed:", ..] => {
+                depth = depth.saturating_sub(1);
+            }
+            ["Program", _, "consumed", units, "of", _, "compute", "units"] if depth == 1 => {
+                consumed += units.parse::<u64>().unwrap_or_default();
+            }
+            _ => {}
+        }
+    }
+    consumed
+}
+
+/// `Transaction` entity, instruction entities link to it by the primary signature
+pub fn transaction_value(
+    block: &SolanaBlock,
+    transaction: &TransactionWithStatusMeta,
+    transaction_index: usize,
+    instruction_count: u64,
+) -> TransportValue {
+    let mut input = TransportValue::new("Transaction");
+    let message = &transaction.transaction.message;
+    input.set_value("id", Value::from(primary_signature(transaction)));
+    input.set_value("block_timestamp", Value::from(block.timestamp));
+    input.set_value("block_number", Value::from(block.block_number));
+    input.set_value("block_hash", Value::from(block.block.blockhash.clone()));
+    input.set_value("transaction_index", Value::from(transaction_index as u64));
+    set_signature_values(transaction, &mut input);
+    set_status_values(transaction, &mut input);
+    input.set_value(
+        "fee_payer",
+        Value::from(message.account_keys.get(0).map(|key| key.to_string())),
+    );
+    if let Some(meta) = transaction.meta.as_ref() {
+        input.set_value("fee", Value::from(meta.fee));
+        let log_messages = meta.log_messages.clone().unwrap_or_default();
+        input.set_value(
+            "compute_units_consumed",
+            Value::from(compute_units_consumed(&log_messages)),
+        );
+        input.set_value(
+            "log_messages",
+            Value::List(log_messages.into_iter().map(Value::from).collect()),
+        );
+    }
+    input.set_value("instruction_count", Value::from(instruction_count));
+    input
+}
+
+/// `IndexingError` entity recording an error, used by the continue error policy
+pub fn error_value(
+    block: &SolanaBlock,
+    transaction: &TransactionWithStatusMeta,
+    position: &InstructionPosition,
+    error: &IndexerError,
+) -> TransportValue {
+    let mut input = TransportValue::new("IndexingError");
+    set_id(transaction, position, &mut input);
+    input.set_value("block_timestamp", Value::from(block.timestamp));
+    set_block_values(block, position, &mut input);
+    set_position_values(position, &mut input);
+    input.set_value("tx_hash", Value::from(primary_signature(transaction)));
+    input.set_value("kind", Value::from(error.kind()));
+    input.set_value("message", Value::from(error.to_string()));
+    input
+}
+
+/// Set the values every instruction entity has: id, signatures, block, position and status
+pub fn set_instruction_values(
+    block: &SolanaBlock,
+    transaction: &TransactionWithStatusMeta,
+    position: &InstructionPosition,
+    input: &mut TransportValue,
+) {
+    set_id(transaction, position, input);
+    set_signature_values(transaction, input);
+    set_block_values(block, position, input);
+    set_position_values(position, input);
+    set_status_values(transaction, input);
+}
+
+/// Derive the id from the primary signature and the instruction position,
+/// the signature is also the id of the linked `Transaction`
+pub fn set_id(
+    transaction: &TransactionWithStatusMeta,
+    position: &InstructionPosition,
+    input: &mut TransportValue,
+) {
+    let signature = primary_signature(transaction).unwrap_or_default();
+    input.set_value("id", Value::from(position.entity_id(&signature)));
+    input.set_value("transaction", Value::from(signature));
+}
+
+/// `tx_hash` is the primary signature, all signatures and signer keys are kept as lists
+pub fn set_signature_values(transaction: &TransactionWithStatusMeta, input: &mut TransportValue) {
+    let message = &transaction.transaction.message;
+    input.set_value("tx_hash", Value::from(primary_signature(transaction)));
+    input.set_value(
+        "signatures",
+        Value::List(
+            transaction
+                .transaction
+                .signatures
+                .iter()
+                .map(|sig| Value::from(sig.to_string()))
+                .collect(),
+        ),
+    );
+    input.set_value(
+        "signers",
+        Value::List(
+            message
+                .account_keys
+                .iter()
+                .take(message.header.num_required_signatures as usize)
+                .map(|key| Value::from(key.to_string()))
+                .collect(),
+        ),
+    );
+}
+
+/// Slot, blockhash and transaction index let events be ordered within a slot
+pub fn set_block_values(
+    block: &SolanaBlock,
+    position: &InstructionPosition,
+    input: &mut TransportValue,
+) {
+    input.set_value("block_number", Value::from(block.block_number));
+    input.set_value("block_hash", Value::from(block.block.blockhash.clone()));
+    input.set_value("parent_slot", Value::from(block.block.parent_slot));
+    input.set_value(
+        "transaction_index",
+        Value::from(position.transaction_index as u64),
+    );
+}
+
+/// Tag the instruction as top-level or inner (CPI), with the index of its parent instruction
+pub fn set_position_values(position: &InstructionPosition, input: &mut TransportValue) {
+    input.set_value("is_inner", Value::from(position.is_inner()));
+    input.set_value(
+        "instruction_index",
+        Value::from(position.instruction_index as u64),
+    );
+    input.set_value(
+        "inner_index",
+        Value::from(position.inner_index.map(|index| index as u64)),
+    );
+}
+
+/// Flag the instruction with the status of its transaction, `error` is null on success
+pub fn set_status_values(transaction: &TransactionWithStatusMeta, input: &mut TransportValue) {
+    let error = transaction
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.status.as_ref().err().map(|err| err.to_string()));
+    input.set_value("success", Value::from(error.is_none()));
+    input.set_value("error", Value::from(error));
+}
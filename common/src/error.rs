@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+/// Errors raised while indexing a block
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error("Cannot decode instruction {instruction_index} of transaction {tx_hash}: {message}")]
+    Decode {
+        tx_hash: String,
+        instruction_index: usize,
+        inner_index: Option<usize>,
+        message: String,
+    },
+    #[error("Cannot write {entity} to the store: {message}")]
+    Store { entity: String, message: String },
+    #[error("Cannot fetch accounts from the RPC node: {0}")]
+    Rpc(String),
+    #[error("Store is not injected into the plugin")]
+    MissingStore,
+    #[error("Instruction parser of program {0} is not injected into the plugin")]
+    MissingInterface(String),
+    #[error("Cannot access the local state: {0}")]
+    LocalState(String),
+}
+
+impl IndexerError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            IndexerError::Decode { .. } => "Decode",
+            IndexerError::Store { .. } => "Store",
+            IndexerError::Rpc(_) => "Rpc",
+            IndexerError::MissingStore => "MissingStore",
+            IndexerError::MissingInterface(_) => "MissingInterface",
+            IndexerError::LocalState(_) => "LocalState",
+        }
+    }
+}
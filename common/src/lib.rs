@@ -0,0 +1,10 @@
+pub mod account_keys;
+pub mod config;
+pub mod context;
+pub mod decode;
+pub mod enrichment;
+pub mod entity;
+pub mod error;
+pub mod local_state;
+pub mod position;
+pub mod token_metadata;
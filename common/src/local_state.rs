@@ -0,0 +1,42 @@
+use crate::error::IndexerError;
+use std::path::Path;
+
+/// On-disk database of the facts learnt from the indexed blocks that the store cannot be queried
+/// for, so they survive a restart of the indexer. Each user keeps its entries in its own tree,
+/// only the most used part of the database is kept in memory.
+#[derive(Clone)]
+pub struct LocalState {
+    db: sled::Db,
+}
+
+impl LocalState {
+    /// Open the database in `path`, with a cache of `cache_size` bytes
+    pub fn open(path: &Path, cache_size: u64) -> Result<Self, IndexerError> {
+        sled::Config::new()
+            .path(path)
+            .cache_capacity(cache_size)
+            .open()
+            .map(|db| LocalState { db })
+            .map_err(|err| IndexerError::LocalState(err.to_string()))
+    }
+    /// Database removed when it is dropped
+    pub fn temporary() -> Result<Self, IndexerError> {
+        sled::Config::new()
+            .temporary(true)
+            .open()
+            .map(|db| LocalState { db })
+            .map_err(|err| IndexerError::LocalState(err.to_string()))
+    }
+    pub fn tree(&self, name: &str) -> Result<sled::Tree, IndexerError> {
+        self.db
+            .open_tree(name)
+            .map_err(|err| IndexerError::LocalState(err.to_string()))
+    }
+    /// Write the pending changes to disk, called after the store is flushed
+    pub fn flush(&self) -> Result<(), IndexerError> {
+        self.db
+            .flush()
+            .map(|_| ())
+            .map_err(|err| IndexerError::LocalState(err.to_string()))
+    }
+}
@@ -0,0 +1,38 @@
+/// Position of an instruction in its block
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InstructionPosition {
+    /// Index of the transaction in the block
+    pub transaction_index: usize,
+    /// Index of the top-level instruction, for an inner instruction this is the parent index
+    pub instruction_index: usize,
+    /// Index in the parent's inner instruction list, `None` for a top-level instruction
+    pub inner_index: Option<usize>,
+}
+impl InstructionPosition {
+    pub fn top_level(transaction_index: usize, instruction_index: usize) -> Self {
+        InstructionPosition {
+            transaction_index,
+            instruction_index,
+            inner_index: None,
+        }
+    }
+    pub fn inner(transaction_index: usize, parent_index: usize, inner_index: usize) -> Self {
+        InstructionPosition {
+            transaction_index,
+            instruction_index: parent_index,
+            inner_index: Some(inner_index),
+        }
+    }
+    pub fn is_inner(&self) -> bool {
+        self.inner_index.is_some()
+    }
+    /// Deterministic entity id, so re-indexing a block upserts the same rows
+    pub fn entity_id(&self, signature: &str) -> String {
+        match self.inner_index {
+            Some(inner_index) => {
+                format!("{}-{}-{}", signature, self.instruction_index, inner_index)
+            }
+            None => format!("{}-{}", signature, self.instruction_index),
+        }
+    }
+}
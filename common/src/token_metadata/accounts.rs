@@ -0,0 +1,207 @@
+/// Account layout of each instruction, in the order documented by the token metadata program.
+/// Optional trailing accounts are listed too, they are only saved when present.
+pub fn account_layout(instruction_name: &str) -> &'static [&'static str] {
+    match instruction_name {
+        "CreateMetadataAccount" | "CreateMetadataAccountV2" => &[
+            "metadata",
+            "mint",
+            "mint_authority",
+            "payer",
+            "update_authority",
+            "system_program",
+            "rent",
+        ],
+        "UpdateMetadataAccount" | "UpdateMetadataAccountV2" => {
+            &["metadata", "current_update_authority"]
+        }
+        "DeprecatedCreateMasterEdition" => &[
+            "edition",
+            "mint",
+            "printing_mint",
+            "one_time_printing_authorization_mint",
+            "update_authority",
+            "printing_mint_authority",
+            "mint_authority",
+            "metadata",
+            "payer",
+            "token_program",
+            "system_program",
+            "rent",
+            "one_time_printing_authorization_mint_authority",
+        ],
+        "DeprecatedMintNewEditionFromMasterEditionViaPrintingToken" => &[
+            "new_metadata",
+            "new_edition",
+            "master_edition",
+            "new_mint",
+            "new_mint_authority",
+            "printing_mint",
+            "printing_token_account",
+            "edition_mark_pda",
+            "burn_authority",
+            "payer",
+            "new_metadata_update_authority",
+            "metadata",
+            "token_program",
+            "system_program",
+            "rent",
+            "reservation_list",
+        ],
+        "UpdatePrimarySaleHappenedViaToken" => &["metadata", "owner", "token_account"],
+        "DeprecatedSetReservationList" => &["master_edition", "reservation_list", "resource"],
+        "DeprecatedCreateReservationList" => &[
+            "reservation_list",
+            "payer",
+            "update_authority",
+            "master_edition",
+            "resource",
+            "metadata",
+            "system_program",
+            "rent",
+        ],
+        "SignMetadata" => &["metadata", "creator"],
+        "DeprecatedMintPrintingTokensViaToken" => &[
+            "destination",
+            "token_account",
+            "one_time_printing_authorization_mint",
+            "printing_mint",
+            "burn_authority",
+            "metadata",
+            "master_edition",
+            "token_program",
+            "rent",
+        ],
+        "DeprecatedMintPrintingTokens" => &[
+            "destination",
+            "printing_mint",
+            "update_authority",
+            "metadata",
+            "master_edition",
+            "token_program",
+            "rent",
+        ],
+        "CreateMasterEdition" | "CreateMasterEditionV3" => &[
+            "edition",
+            "mint",
+            "update_authority",
+            "mint_authority",
+            "payer",
+            "metadata",
+            "token_program",
+            "system_program",
+            "rent",
+        ],
+        "MintNewEditionFromMasterEditionViaToken" => &[
+            "new_metadata",
+            "new_edition",
+            "master_edition",
+            "new_mint",
+            "edition_mark_pda",
+            "new_mint_authority",
+            "payer",
+            "token_account_owner",
+            "token_account",
+            "new_metadata_update_authority",
+            "metadata",
+            "token_program",
+            "system_program",
+            "rent",
+        ],
+        "ConvertMasterEditionV1ToV2" => &[
+            "master_edition",
+            "one_time_printing_authorization_mint",
+            "printing_mint",
+        ],
+        "MintNewEditionFromMasterEditionViaVaultProxy" => &[
+            "new_metadata",
+            "new_edition",
+            "master_edition",
+            "new_mint",
+            "edition_mark_pda",
+            "new_mint_authority",
+            "payer",
+            "vault_authority",
+            "safety_deposit_store",
+            "safety_deposit_box",
+            "vault",
+            "new_metadata_update_authority",
+            "metadata",
+            "token_program",
+            "token_vault_program",
+            "system_program",
+            "rent",
+        ],
+        "PuffMetadata" => &["metadata"],
+        "VerifyCollection" => &[
+            "metadata",
+            "collection_authority",
+            "payer",
+            "collection_mint",
+            "collection_metadata",
+            "collection_master_edition",
+            "collection_authority_record",
+        ],
+        "Utilize" => &[
+            "metadata",
+            "token_account",
+            "mint",
+            "use_authority",
+            "owner",
+            "token_program",
+            "ata_program",
+            "system_program",
+            "rent",
+            "use_authority_record",
+            "burner",
+        ],
+        "ApproveUseAuthority" => &[
+            "use_authority_record",
+            "owner",
+            "payer",
+            "user",
+            "owner_token_account",
+            "metadata",
+            "mint",
+            "burner",
+            "token_program",
+            "system_program",
+            "rent",
+        ],
+        "RevokeUseAuthority" => &[
+            "use_authority_record",
+            "owner",
+            "user",
+            "owner_token_account",
+            "mint",
+            "metadata",
+            "token_program",
+            "system_program",
+            "rent",
+        ],
+        "UnverifyCollection" => &[
+            "metadata",
+            "collection_authority",
+            "collection_mint",
+            "collection_metadata",
+            "collection_master_edition",
+            "collection_authority_record",
+        ],
+        "ApproveCollectionAuthority" => &[
+            "collection_authority_record",
+            "new_collection_authority",
+            "update_authority",
+            "payer",
+            "metadata",
+            "mint",
+            "system_program",
+            "rent",
+        ],
+        "RevokeCollectionAuthority" => &[
+            "collection_authority_record",
+            "update_authority",
+            "metadata",
+            "mint",
+        ],
+        _ => &[],
+    }
+}
@@ -0,0 +1,758 @@
+use super::accounts::account_layout;
+use super::pda::{mint_role, pda_layout};
+use crate::context::EntityBatch;
+use crate::enrichment::AccountStates;
+use crate::entity::{set_instruction_values, TransportValueExt};
+use crate::error::IndexerError;
+use crate::position::InstructionPosition;
+use massbit_solana_sdk::{
+    transport::{TransportValue, Value as TransValue},
+    types::SolanaBlock,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use solana_transaction_status::TransactionWithStatusMeta;
+
+pub struct Handler<'a> {
+    pub batch: &'a EntityBatch,
+    /// State of the accounts referenced in the block, empty unless enrichment is enabled
+    pub accounts: &'a AccountStates,
+}
+impl<'a> Handler<'a> {
+    /// State of an account fetched by the enrichment stage, no RPC call is made here
+    pub fn account(&self, key: &Pubkey) -> Option<&Account> {
+        self.accounts.get(key).map(|state| &state.account)
+    }
+    pub fn process(
+        &self,
+        block: &SolanaBlock,
+        transaction: &TransactionWithStatusMeta,
+        position: &InstructionPosition,
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+        mut input: TransportValue,
+    ) -> Result<(), IndexerError> {
+        //println!("Process block {} with input {:?}", block.block_number, input);
+        set_instruction_values(block, transaction, position, &mut input);
+        self.set_account_values(accounts, &mut input);
+        self.set_pda_values(program_id, accounts, &mut input);
+        match input.name.as_str() {
+            "CreateMetadataAccount" => self.process_create_metadata_account(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "UpdateMetadataAccount" => self.process_update_metadata_account(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedCreateMasterEdition" => self.process_deprecated_create_master_edition(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedMintNewEditionFromMasterEditionViaPrintingToken" => self
+                .process_deprecated_mint_new_edition_from_master_edition_via_printing_token(
+                    block,
+                    transaction,
+                    program_id,
+                    accounts,
+                    &mut input,
+                ),
+            "UpdatePrimarySaleHappenedViaToken" => self
+                .process_update_primary_sale_happened_via_token(
+                    block,
+                    transaction,
+                    program_id,
+                    accounts,
+                    &mut input,
+                ),
+            "DeprecatedSetReservationList" => self.process_deprecated_set_reservation_list(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedCreateReservationList" => self.process_deprecated_create_reservation_list(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "SignMetadata" => {
+                self.process_sign_metadata(block, transaction, program_id, accounts, &mut input)
+            }
+            "DeprecatedMintPrintingTokensViaToken" => self
+                .process_deprecated_mint_printing_tokens_via_token(
+                    block,
+                    transaction,
+                    program_id,
+                    accounts,
+                    &mut input,
+                ),
+            "DeprecatedMintPrintingTokens" => self.process_deprecated_mint_printing_tokens(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "CreateMasterEdition" => self.process_create_master_edition(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "MintNewEditionFromMasterEditionViaToken" => self
+                .process_mint_new_edition_from_master_edition_via_token(
+                    block,
+                    transaction,
+                    program_id,
+                    accounts,
+                    &mut input,
+                ),
+            "ConvertMasterEditionV1ToV2" => self.process_convert_master_edition_v1_to_v2(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "MintNewEditionFromMasterEditionViaVaultProxy" => self
+                .process_mint_new_edition_from_master_edition_via_vault_proxy(
+                    block,
+                    transaction,
+                    program_id,
+                    accounts,
+                    &mut input,
+                ),
+            "PuffMetadata" => {
+                self.process_puff_metadata(block, transaction, program_id, accounts, &mut input)
+            }
+            "UpdateMetadataAccountV2" => self.process_update_metadata_account_v2(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "CreateMetadataAccountV2" => self.process_create_metadata_account_v2(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "CreateMasterEditionV3" => self.process_create_master_edition_v3(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "VerifyCollection" => {
+                self.process_verify_collection(block, transaction, program_id, accounts, &mut input)
+            }
+            "Utilize" => self.process_utilize(block, transaction, program_id, accounts, &mut input),
+            "ApproveUseAuthority" => self.process_approve_use_authority(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "RevokeUseAuthority" => self.process_revoke_use_authority(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "UnverifyCollection" => self.process_unverify_collection(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "ApproveCollectionAuthority" => self.process_approve_collection_authority(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "RevokeCollectionAuthority" => self.process_revoke_collection_authority(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            _ => Ok(()),
+        }
+    }
+    /// Save each account under its role name in the instruction account layout
+    fn set_account_values(&self, accounts: &[Pubkey], input: &mut TransportValue) {
+        for (role, account) in account_layout(input.name.as_str())
+            .iter()
+            .zip(accounts.iter())
+        {
+            input.set_value(*role, TransValue::from(account.to_string()));
+        }
+    }
+    /// Set the mint as the join key and check the accounts derived from a mint, accounts that
+    /// differ from the derived address are listed in `pda_mismatches` and flag the instruction
+    /// as suspicious. Without a mint account the mint is left null, `MintIndex::join` sets it
+    /// from the metadata account once the block is decoded.
+    fn set_pda_values(&self, program_id: &Pubkey, accounts: &[Pubkey], input: &mut TransportValue) {
+        let roles = account_layout(input.name.as_str());
+        let account = |role: &str| {
+            roles
+                .iter()
+                .position(|name| *name == role)
+                .and_then(|ind| accounts.get(ind))
+        };
+        let mut mismatches = Vec::new();
+        for (role, mint_role, kind) in pda_layout(input.name.as_str()) {
+            if let (Some(address), Some(mint)) = (account(*role), account(*mint_role)) {
+                if *address != kind.find_address(program_id, mint) {
+                    mismatches.push(TransValue::from(*role));
+                }
+            }
+        }
+        let mint = mint_role(input.name.as_str()).and_then(account);
+        input.set_value("mint", TransValue::from(mint.map(|mint| mint.to_string())));
+        input.set_value("suspicious", TransValue::from(!mismatches.is_empty()));
+        input.set_value("pda_mismatches", TransValue::List(mismatches));
+    }
+    fn process_create_metadata_account(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_update_metadata_account(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_deprecated_create_master_edition(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_deprecated_mint_new_edition_from_master_edition_via_printing_token(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_update_primary_sale_happened_via_token(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_deprecated_set_reservation_list(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_deprecated_create_reservation_list(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_sign_metadata(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_deprecated_mint_printing_tokens_via_token(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_deprecated_mint_printing_tokens(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_create_master_edition(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_mint_new_edition_from_master_edition_via_token(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_convert_master_edition_v1_to_v2(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_mint_new_edition_from_master_edition_via_vault_proxy(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_puff_metadata(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_update_metadata_account_v2(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_create_metadata_account_v2(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_create_master_edition_v3(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_verify_collection(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_utilize(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_approve_use_authority(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_revoke_use_authority(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_unverify_collection(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+        input.set_value(
+            "account_name",
+            TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
+        );
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_approve_collection_authority(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+    fn process_revoke_collection_authority(
+        &self,
+        block: &SolanaBlock,
+        _transaction: &TransactionWithStatusMeta,
+        _program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: &mut TransportValue,
+    ) -> Result<(), IndexerError> {
+        println!(
+            "call function process_initialize for handle incoming block {} with argument {:?}",
+            block.block_number, &input.name
+        );
+        input.set_value("block_timestamp", TransValue::from(block.timestamp));
+
+        input.save(self.batch)?;
+        println!("Write to db {:?}", input);
+        Ok(())
+    }
+}
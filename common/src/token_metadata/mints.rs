@@ -0,0 +1,135 @@
+use super::accounts::account_layout;
+use super::nft::{creator_values, nft_value};
+use super::pda::{metadata_mint, pda_layout};
+use crate::enrichment::AccountStates;
+use crate::error::IndexerError;
+use crate::local_state::LocalState;
+use massbit_solana_sdk::transport::{TransportValue, Value};
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Roles an instruction without a mint account is joined through, in order
+const JOIN_ROLES: [&str; 3] = ["metadata", "master_edition", "edition"];
+
+/// Mint of the metadata and edition accounts seen by the indexer, used to join the instructions
+/// that only reference one of these accounts. The accounts are derived from their mint, so a pair
+/// never changes and is kept in the local state, across blocks and restarts.
+pub struct MintIndex {
+    mints: sled::Tree,
+}
+
+impl MintIndex {
+    pub fn new(state: &LocalState) -> Result<Self, IndexerError> {
+        Ok(MintIndex {
+            mints: state.tree("mints")?,
+        })
+    }
+    pub fn get(&self, account: &Pubkey) -> Option<Pubkey> {
+        match self.mints.get(account) {
+            Ok(mint) => mint.map(|mint| Pubkey::new(&mint)),
+            Err(err) => {
+                println!("Warning: cannot read the mint of {}: {}", account, err);
+                None
+            }
+        }
+    }
+    pub fn insert(&self, account: Pubkey, mint: Pubkey) {
+        if let Err(err) = self.mints.insert(account, mint.as_ref()) {
+            println!("Warning: cannot record the mint of {}: {}", account, err);
+        }
+    }
+    /// Join the instruction entities of a transaction to the mint of their token, followed by the
+    /// `Nft`, `Creator` and `NftCreator` rows of the instructions of successful transactions.
+    /// Run in write order after the parallel decoding: the metadata accounts an instruction
+    /// derives from its mint are recorded first, so an instruction can be joined through a
+    /// metadata account created earlier in the block without decoding the block twice.
+    pub fn join(&self, values: Vec<TransportValue>, slot: u64) -> Vec<TransportValue> {
+        let mut joined = Vec::with_capacity(values.len());
+        for mut value in values {
+            if account_layout(value.name.as_str()).is_empty() {
+                joined.push(value);
+                continue;
+            }
+            self.index_instruction(&value);
+            let mint = match pubkey_value(&value, "mint") {
+                Some(mint) => Some(mint),
+                None => self.resolve(&mut value),
+            };
+            let success = value.values.get("success") != Some(&Value::from(false));
+            let rows = match mint {
+                Some(mint) if success => {
+                    let mint = mint.to_string();
+                    let mut rows = nft_value(&value, &mint, slot)
+                        .into_iter()
+                        .collect::<Vec<TransportValue>>();
+                    rows.extend(creator_values(&value, &mint, slot));
+                    rows
+                }
+                _ => Vec::new(),
+            };
+            joined.push(value);
+            joined.extend(rows);
+        }
+        joined
+    }
+    /// Record the metadata and edition accounts of an instruction entity that are derived from
+    /// its mint accounts, the derivation is checked when the instruction is decoded
+    pub fn index_instruction(&self, value: &TransportValue) {
+        let mismatches = match value.values.get("pda_mismatches") {
+            Some(Value::List(roles)) => roles.clone(),
+            _ => Vec::new(),
+        };
+        for (role, mint_role, _) in pda_layout(value.name.as_str()) {
+            if mismatches.contains(&Value::from(*role)) {
+                continue;
+            }
+            if let (Some(account), Some(mint)) =
+                (pubkey_value(value, role), pubkey_value(value, mint_role))
+            {
+                self.insert(account, mint);
+            }
+        }
+    }
+    /// Record the mint stored in the metadata accounts fetched by the enrichment stage
+    pub fn index_accounts(&self, program_id: &Pubkey, accounts: &AccountStates) {
+        for (key, state) in accounts.iter() {
+            if state.account.owner != *program_id {
+                continue;
+            }
+            if let Some(mint) = metadata_mint(&state.account.data) {
+                self.insert(*key, mint);
+            }
+        }
+    }
+    /// Set the mint of a `MasterEditionState` or `EditionState`, edition accounts do not store it
+    pub fn set_state_mint(&self, address: &Pubkey, value: &mut TransportValue) {
+        if let "MasterEditionState" | "EditionState" = value.name.as_str() {
+            let mint = self.get(address).map(|mint| mint.to_string());
+            value.set_value("mint", Value::from(mint));
+        }
+    }
+    /// Set the mint of an instruction without a mint account from its metadata or edition account
+    fn resolve(&self, value: &mut TransportValue) -> Option<Pubkey> {
+        let account = JOIN_ROLES
+            .iter()
+            .find_map(|role| pubkey_value(value, role))?;
+        let mint = self.get(&account);
+        match mint {
+            Some(mint) => value.set_value("mint", Value::from(mint.to_string())),
+            None => println!(
+                "Warning: unknown mint of account {}, {} is not joined to its Nft. \
+                 Enable ENRICH_ACCOUNTS for tokens created before the start block",
+                account, value.name
+            ),
+        }
+        mint
+    }
+}
+
+/// Account saved under a role of an instruction entity
+fn pubkey_value(value: &TransportValue, role: &str) -> Option<Pubkey> {
+    match value.values.get(role) {
+        Some(Value::String(address)) => Pubkey::from_str(address).ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,7 @@
+pub mod accounts;
+pub mod handler;
+pub mod mints;
+pub mod nft;
+pub mod pda;
+pub mod rows;
+pub mod state;
@@ -0,0 +1,183 @@
+use massbit_solana_sdk::transport::{TransportValue, Value};
+
+/// Entity aggregating the latest known state of each token, keyed by mint
+pub const NFT_ENTITY: &str = "Nft";
+/// Entity of a creator address, keyed by address
+pub const CREATOR_ENTITY: &str = "Creator";
+/// Join entity of a token and one of its creators, keyed by `{mint}-{creator}`
+pub const NFT_CREATOR_ENTITY: &str = "NftCreator";
+/// History row of a creator verified or unverified on a token
+pub const CREATOR_VERIFICATION_ENTITY: &str = "CreatorVerification";
+
+/// Instruction values copied to the `Nft` entity when the instruction sets them
+pub const NFT_FIELDS: [&str; 11] = [
+    "name",
+    "symbol",
+    "uri",
+    "seller_fee_basis_points",
+    "creator_addresses",
+    "creator_verified",
+    "creator_shares",
+    "is_mutable",
+    "primary_sale_happened",
+    "collection_key",
+    "collection_verified",
+];
+
+/// Build the `Nft` upsert for an instruction, `None` if the instruction does not change the token.
+/// Only the fields known from the instruction are set, the store merges them into the saved row
+pub fn nft_value(input: &TransportValue, mint: &str, slot: u64) -> Option<TransportValue> {
+    let name = input.name.as_str();
+    let mut nft = TransportValue::new(NFT_ENTITY);
+    match name {
+        "CreateMetadataAccount" | "CreateMetadataAccountV2" => {
+            nft.set_value("created_at", Value::from(slot));
+            nft.set_value("primary_sale_happened", Value::from(false));
+            copy_value(input, "update_authority", &mut nft);
+        }
+        "UpdateMetadataAccount" | "UpdateMetadataAccountV2" => {
+            copy_value(input, "update_authority", &mut nft);
+        }
+        "UpdatePrimarySaleHappenedViaToken" => {
+            nft.set_value("primary_sale_happened", Value::from(true));
+        }
+        "VerifyCollection" => {
+            if let Some(collection) = input.values.get("collection_mint") {
+                nft.set_value("collection_key", collection.clone());
+            }
+            nft.set_value("collection_verified", Value::from(true));
+        }
+        "UnverifyCollection" => {
+            nft.set_value("collection_verified", Value::from(false));
+        }
+        "SignMetadata" | "PuffMetadata" => {}
+        _ => return None,
+    }
+    for field in NFT_FIELDS.iter() {
+        copy_value(input, field, &mut nft);
+    }
+    if input.values.contains_key("creator_addresses") {
+        nft.set_value("creators_updated_at", Value::from(slot));
+    }
+    nft.set_value("id", Value::from(mint));
+    nft.set_value("mint", Value::from(mint));
+    copy_value(input, "metadata", &mut nft);
+    nft.set_value("updated_at", Value::from(slot));
+    nft.set_value("last_instruction", Value::from(name));
+    Some(nft)
+}
+
+/// A creator of a token as set by an instruction
+struct CreatorEntry {
+    address: String,
+    position: Option<usize>,
+    share: Option<Value>,
+    verified: bool,
+}
+
+/// Build the `Creator` and `NftCreator` upserts of an instruction with the `CreatorVerification`
+/// history rows. Creators come from the creators array of the metadata data, `SignMetadata`
+/// verifies its signer with a partial `NftCreator` row. The creators of the array are `active`,
+/// `NftRows` deactivates the creators an update removes.
+/// History rows are written for every creator of an update, `NftRows` drops the ones that do not
+/// change the status of a known creator.
+pub fn creator_values(input: &TransportValue, mint: &str, slot: u64) -> Vec<TransportValue> {
+    let name = input.name.as_str();
+    let (creators, history) = match name {
+        "SignMetadata" => {
+            let signer = input.values.get("creator").and_then(as_str);
+            let creators = signer
+                .map(|address| CreatorEntry {
+                    address: address.to_string(),
+                    position: None,
+                    share: None,
+                    verified: true,
+                })
+                .into_iter()
+                .collect();
+            (creators, true)
+        }
+        // At creation only verified creators get a history row, an update may also unverify
+        "CreateMetadataAccount" | "CreateMetadataAccountV2" => (data_creators(input), false),
+        "UpdateMetadataAccount" | "UpdateMetadataAccountV2" => (data_creators(input), true),
+        _ => (Vec::new(), false),
+    };
+    let instruction_id = input.values.get("id").and_then(as_str).unwrap_or_default();
+    let mut values = Vec::new();
+    for creator in creators {
+        let mut value = TransportValue::new(CREATOR_ENTITY);
+        value.set_value("id", Value::from(creator.address.as_str()));
+        value.set_value("address", Value::from(creator.address.as_str()));
+        value.set_value("last_seen_at", Value::from(slot));
+        values.push(value);
+
+        let mut value = TransportValue::new(NFT_CREATOR_ENTITY);
+        value.set_value("id", Value::from(format!("{}-{}", mint, creator.address)));
+        value.set_value("nft", Value::from(mint));
+        value.set_value("creator", Value::from(creator.address.as_str()));
+        if let Some(position) = creator.position {
+            value.set_value("position", Value::from(position as u64));
+            value.set_value("active", Value::from(true));
+        }
+        if let Some(share) = creator.share {
+            value.set_value("share", share);
+        }
+        value.set_value("verified", Value::from(creator.verified));
+        value.set_value("updated_at", Value::from(slot));
+        values.push(value);
+
+        if history || creator.verified {
+            let mut value = TransportValue::new(CREATOR_VERIFICATION_ENTITY);
+            value.set_value(
+                "id",
+                Value::from(format!("{}-{}", instruction_id, creator.address)),
+            );
+            value.set_value("nft", Value::from(mint));
+            value.set_value("creator", Value::from(creator.address.as_str()));
+            value.set_value("verified", Value::from(creator.verified));
+            value.set_value("slot", Value::from(slot));
+            copy_value(input, "tx_hash", &mut value);
+            value.set_value("instruction", Value::from(name));
+            values.push(value);
+        }
+    }
+    values
+}
+
+/// Read the creators array from the parallel creator lists of the metadata data
+fn data_creators(input: &TransportValue) -> Vec<CreatorEntry> {
+    let list = |field: &str| match input.values.get(field) {
+        Some(Value::List(items)) => items.clone(),
+        _ => Vec::new(),
+    };
+    let shares = list("creator_shares");
+    let verified = list("creator_verified");
+    list("creator_addresses")
+        .iter()
+        .enumerate()
+        .filter_map(|(position, address)| {
+            Some(CreatorEntry {
+                address: as_str(address)?.to_string(),
+                position: Some(position),
+                share: shares.get(position).cloned(),
+                verified: verified.get(position) == Some(&Value::from(true)),
+            })
+        })
+        .collect()
+}
+
+/// Addresses are saved as string values
+fn as_str(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(value) => Some(value.as_str()),
+        _ => None,
+    }
+}
+
+/// Copy a value unless the instruction left it unset
+fn copy_value(input: &TransportValue, field: &str, entity: &mut TransportValue) {
+    match input.values.get(field) {
+        Some(Value::Null) | None => {}
+        Some(value) => entity.set_value(field, value.clone()),
+    }
+}
@@ -0,0 +1,109 @@
+use mpl_token_metadata::state::Key;
+use solana_program::pubkey::Pubkey;
+
+/// Seed prefix of the metadata and edition accounts
+pub const PREFIX: &str = "metadata";
+/// Seed suffix of the edition accounts, shared by master editions and printed editions
+pub const EDITION: &str = "edition";
+
+/// Accounts the token metadata program derives from a mint
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PdaKind {
+    /// `["metadata", program_id, mint]`
+    Metadata,
+    /// `["metadata", program_id, mint, "edition"]`
+    Edition,
+}
+
+impl PdaKind {
+    pub fn find_address(&self, program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+        match self {
+            PdaKind::Metadata => find_metadata_address(program_id, mint),
+            PdaKind::Edition => find_edition_address(program_id, mint),
+        }
+    }
+}
+
+pub fn find_metadata_address(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PREFIX.as_bytes(), program_id.as_ref(), mint.as_ref()],
+        program_id,
+    )
+    .0
+}
+
+pub fn find_edition_address(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            program_id.as_ref(),
+            mint.as_ref(),
+            EDITION.as_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// PDA accounts of each instruction: the account role, the role of the mint it is derived from
+/// and the kind of account. Roles are the ones of `account_layout`.
+pub fn pda_layout(instruction_name: &str) -> &'static [(&'static str, &'static str, PdaKind)] {
+    match instruction_name {
+        "CreateMetadataAccount"
+        | "CreateMetadataAccountV2"
+        | "Utilize"
+        | "ApproveUseAuthority"
+        | "RevokeUseAuthority"
+        | "ApproveCollectionAuthority"
+        | "RevokeCollectionAuthority" => &[("metadata", "mint", PdaKind::Metadata)],
+        "DeprecatedCreateMasterEdition" | "CreateMasterEdition" | "CreateMasterEditionV3" => &[
+            ("edition", "mint", PdaKind::Edition),
+            ("metadata", "mint", PdaKind::Metadata),
+        ],
+        "DeprecatedMintNewEditionFromMasterEditionViaPrintingToken"
+        | "MintNewEditionFromMasterEditionViaToken"
+        | "MintNewEditionFromMasterEditionViaVaultProxy" => &[
+            ("new_metadata", "new_mint", PdaKind::Metadata),
+            ("new_edition", "new_mint", PdaKind::Edition),
+        ],
+        "VerifyCollection" | "UnverifyCollection" => &[
+            ("collection_metadata", "collection_mint", PdaKind::Metadata),
+            (
+                "collection_master_edition",
+                "collection_mint",
+                PdaKind::Edition,
+            ),
+        ],
+        _ => &[],
+    }
+}
+
+/// Role of the mint an instruction is about, used as the join key.
+/// Instructions without a mint account are joined through the mint of their metadata or edition
+/// account.
+pub fn mint_role(instruction_name: &str) -> Option<&'static str> {
+    match instruction_name {
+        "CreateMetadataAccount"
+        | "CreateMetadataAccountV2"
+        | "DeprecatedCreateMasterEdition"
+        | "CreateMasterEdition"
+        | "CreateMasterEditionV3"
+        | "Utilize"
+        | "ApproveUseAuthority"
+        | "RevokeUseAuthority"
+        | "ApproveCollectionAuthority"
+        | "RevokeCollectionAuthority" => Some("mint"),
+        "DeprecatedMintNewEditionFromMasterEditionViaPrintingToken"
+        | "MintNewEditionFromMasterEditionViaToken"
+        | "MintNewEditionFromMasterEditionViaVaultProxy" => Some("new_mint"),
+        _ => None,
+    }
+}
+
+/// Mint stored in the data of a `Metadata` account, after the key and the update authority
+pub fn metadata_mint(data: &[u8]) -> Option<Pubkey> {
+    match data.get(0) {
+        Some(&key) if key == Key::MetadataV1 as u8 => data.get(33..65).map(Pubkey::new),
+        _ => None,
+    }
+}
@@ -0,0 +1,247 @@
+use super::nft::{CREATOR_VERIFICATION_ENTITY, NFT_CREATOR_ENTITY, NFT_ENTITY};
+use crate::error::IndexerError;
+use crate::local_state::LocalState;
+use borsh::{BorshDeserialize, BorshSerialize};
+use massbit_solana_sdk::transport::{TransportValue, Value};
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+type Row = HashMap<String, Value>;
+
+/// Creators of a token as last written to its `Nft` row, in position order
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct KnownCreators {
+    pub addresses: Vec<String>,
+    pub verified: Vec<bool>,
+}
+
+/// Derived state of the `Nft` and `NftCreator` rows written by the indexer.
+/// Instructions only carry the fields they change, and their partial rows are written as they
+/// are: the store merges an update into the saved entity and only overwrites the fields it sets.
+/// The creators of each token are kept in the local state, to record a `CreatorVerification` only
+/// when the status of a creator changes, to keep `Nft.creator_verified` in sync with the
+/// `NftCreator` rows and to deactivate the `NftCreator` of a creator removed by an update. Rows must be merged in write order, on the thread writing the store.
+///
+/// Changes are pending until `commit` is called after the store is flushed, and are dropped by
+/// `discard` when a block fails, so the local state never runs ahead of the store.
+pub struct NftRows {
+    creators: sled::Tree,
+    pending: Mutex<HashMap<String, KnownCreators>>,
+}
+
+impl NftRows {
+    pub fn new(state: &LocalState) -> Result<Self, IndexerError> {
+        Ok(NftRows {
+            creators: state.tree("nft_creators")?,
+            pending: Mutex::new(HashMap::new()),
+        })
+    }
+    /// Creators of a token, `None` until an instruction with the creators array is merged
+    pub fn creators(&self, mint: &str) -> Option<KnownCreators> {
+        if let Some(creators) = self.lock().get(mint) {
+            return Some(creators.clone());
+        }
+        match self.creators.get(mint) {
+            Ok(creators) => creators.and_then(|bytes| KnownCreators::try_from_slice(&bytes).ok()),
+            Err(err) => {
+                println!("Warning: cannot read the creators of {}: {}", mint, err);
+                None
+            }
+        }
+    }
+    /// Update the creators of the `Nft` and `NftCreator` rows of a transaction, other entities
+    /// are returned unchanged. A `CreatorVerification` row is dropped when the creator already
+    /// had the same status, and a creator verified or unverified on its own is also updated in
+    /// `Nft.creator_verified`. A creators array is followed by an inactive `NftCreator` row for
+    /// each known creator it removes.
+    pub fn merge(&self, values: Vec<TransportValue>) -> Vec<TransportValue> {
+        let mut merged = Vec::with_capacity(values.len());
+        // Status of each creator before the instruction changing it, `None` when it is not known
+        let mut previous = HashMap::new();
+        for value in values {
+            match value.name.as_str() {
+                NFT_ENTITY => {
+                    let removed = self.merge_nft(&value, &mut previous);
+                    merged.push(value);
+                    merged.extend(removed);
+                    continue;
+                }
+                NFT_CREATOR_ENTITY => self.merge_nft_creator(&value, &mut previous, &mut merged),
+                CREATOR_VERIFICATION_ENTITY if is_unchanged(&value, &previous) => continue,
+                _ => {}
+            }
+            merged.push(value);
+        }
+        merged
+    }
+    /// Save the pending changes, called once the blocks they come from are flushed to the store
+    pub fn commit(&self) -> Result<(), IndexerError> {
+        let mut pending = self.lock();
+        let mut batch = sled::Batch::default();
+        for (mint, creators) in pending.iter() {
+            let bytes = creators
+                .try_to_vec()
+                .map_err(|err| IndexerError::LocalState(err.to_string()))?;
+            batch.insert(mint.as_bytes(), bytes);
+        }
+        self.creators
+            .apply_batch(batch)
+            .map_err(|err| IndexerError::LocalState(err.to_string()))?;
+        pending.clear();
+        Ok(())
+    }
+    /// Drop the pending changes of the blocks that were not flushed
+    pub fn discard(&self) {
+        self.lock().clear();
+    }
+    /// Record the creators array of an `Nft` row, returns the inactive `NftCreator` rows of the
+    /// creators it removes
+    fn merge_nft(
+        &self,
+        nft: &TransportValue,
+        previous: &mut HashMap<String, Option<bool>>,
+    ) -> Vec<TransportValue> {
+        let (mint, addresses) = match (
+            string_value(&nft.values, "id"),
+            nft.values.get("creator_addresses"),
+        ) {
+            (Some(mint), Some(Value::List(addresses))) => (mint, addresses),
+            _ => return Vec::new(),
+        };
+        let verified = match nft.values.get("creator_verified") {
+            Some(Value::List(verified)) => verified.clone(),
+            _ => Vec::new(),
+        };
+        let known = self.creators(&mint);
+        let creators = KnownCreators {
+            addresses: addresses.iter().filter_map(as_string).collect(),
+            verified: (0..addresses.len())
+                .map(|position| verified.get(position) == Some(&Value::from(true)))
+                .collect(),
+        };
+        for address in creators.addresses.iter() {
+            let status = known.as_ref().and_then(|known| known.status(address));
+            previous.insert(format!("{}-{}", mint, address), status);
+        }
+        let removed = known
+            .iter()
+            .flat_map(|known| known.addresses.iter())
+            .filter(|address| !creators.addresses.contains(address))
+            .map(|address| {
+                let mut value = TransportValue::new(NFT_CREATOR_ENTITY);
+                value.set_value("id", Value::from(format!("{}-{}", mint, address)));
+                value.set_value("nft", Value::from(mint.as_str()));
+                value.set_value("creator", Value::from(address.as_str()));
+                value.set_value("position", Value::Null);
+                value.set_value("active", Value::from(false));
+                if let Some(slot) = nft.values.get("updated_at") {
+                    value.set_value("updated_at", slot.clone());
+                }
+                value
+            })
+            .collect();
+        self.lock().insert(mint, creators);
+        removed
+    }
+    /// Apply the status of a creator verified or unverified on its own, a row of the creators
+    /// array was already recorded with its `Nft` row
+    fn merge_nft_creator(
+        &self,
+        nft_creator: &TransportValue,
+        previous: &mut HashMap<String, Option<bool>>,
+        merged: &mut Vec<TransportValue>,
+    ) {
+        if nft_creator.values.contains_key("position") {
+            return;
+        }
+        let (id, mint, creator, verified) = match (
+            string_value(&nft_creator.values, "id"),
+            string_value(&nft_creator.values, "nft"),
+            string_value(&nft_creator.values, "creator"),
+            bool_value(&nft_creator.values, "verified"),
+        ) {
+            (Some(id), Some(mint), Some(creator), Some(verified)) => (id, mint, creator, verified),
+            _ => return,
+        };
+        let mut creators = match self.creators(&mint) {
+            Some(creators) => creators,
+            None => {
+                previous.insert(id, None);
+                return;
+            }
+        };
+        previous.insert(id, creators.status(&creator));
+        let position = match creators
+            .addresses
+            .iter()
+            .position(|address| *address == creator)
+        {
+            Some(position) if creators.verified[position] != verified => position,
+            _ => return,
+        };
+        creators.verified[position] = verified;
+        let list = Value::List(creators.verified.iter().map(|v| Value::from(*v)).collect());
+        self.lock().insert(mint.clone(), creators);
+        let written = merged.iter_mut().rev().find(|value| {
+            value.name == NFT_ENTITY && string_value(&value.values, "id").as_ref() == Some(&mint)
+        });
+        match written {
+            Some(value) => value.set_value("creator_verified", list),
+            None => {
+                let mut nft = TransportValue::new(NFT_ENTITY);
+                nft.set_value("id", Value::from(mint));
+                nft.set_value("creator_verified", list);
+                merged.push(nft);
+            }
+        }
+    }
+    fn lock(&self) -> MutexGuard<HashMap<String, KnownCreators>> {
+        self.pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl KnownCreators {
+    /// Status of a creator, `None` when it is not a creator of the token
+    pub fn status(&self, address: &str) -> Option<bool> {
+        self.addresses
+            .iter()
+            .position(|known| known == address)
+            .and_then(|position| self.verified.get(position).copied())
+    }
+}
+
+/// True when a `CreatorVerification` row records the status the creator already had
+fn is_unchanged(value: &TransportValue, previous: &HashMap<String, Option<bool>>) -> bool {
+    let id = match (
+        string_value(&value.values, "nft"),
+        string_value(&value.values, "creator"),
+    ) {
+        (Some(mint), Some(creator)) => format!("{}-{}", mint, creator),
+        _ => return false,
+    };
+    match (previous.get(&id), bool_value(&value.values, "verified")) {
+        (Some(Some(before)), Some(verified)) => *before == verified,
+        _ => false,
+    }
+}
+
+fn as_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+fn bool_value(values: &Row, field: &str) -> Option<bool> {
+    match values.get(field) {
+        Some(value) if *value == Value::from(true) => Some(true),
+        Some(value) if *value == Value::from(false) => Some(false),
+        _ => None,
+    }
+}
+
+fn string_value(values: &Row, field: &str) -> Option<String> {
+    values.get(field).and_then(as_string)
+}
@@ -0,0 +1,147 @@
+use borsh::BorshDeserialize;
+use massbit_solana_sdk::transport::{TransportValue, Value};
+use mpl_token_metadata::state::{Creator, Edition, Key, MasterEditionV2, Metadata};
+use solana_program::pubkey::Pubkey;
+
+/// Where an account state was read from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StateSource {
+    /// Fetched by the enrichment stage while handling a block
+    Enrichment,
+    /// Pushed by the host with `handle_account_update`
+    AccountUpdate,
+}
+
+impl StateSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StateSource::Enrichment => "enrichment",
+            StateSource::AccountUpdate => "account_update",
+        }
+    }
+}
+
+/// Decode the data of a Token Metadata account into a state entity keyed by the account address:
+/// `MetadataState`, `MasterEditionState` or `EditionState`.
+/// Returns `None` for other account types and for data that cannot be decoded.
+pub fn decode_account(
+    address: &Pubkey,
+    data: &[u8],
+    slot: u64,
+    source: StateSource,
+) -> Option<TransportValue> {
+    let key = *data.first()?;
+    // Accounts are allocated with their maximum size, `deserialize` ignores the trailing padding
+    let decoded = if key == Key::MetadataV1 as u8 {
+        Metadata::deserialize(&mut &data[..]).map(metadata_values)
+    } else if key == Key::MasterEditionV2 as u8 {
+        MasterEditionV2::deserialize(&mut &data[..]).map(master_edition_values)
+    } else if key == Key::EditionV1 as u8 {
+        Edition::deserialize(&mut &data[..]).map(edition_values)
+    } else {
+        return None;
+    };
+    match decoded {
+        Ok(mut input) => {
+            input.set_value("id", Value::from(address.to_string()));
+            input.set_value("slot", Value::from(slot));
+            input.set_value("source", Value::from(source.as_str()));
+            Some(input)
+        }
+        Err(err) => {
+            println!("Cannot decode account {}: {:?}", address, err);
+            None
+        }
+    }
+}
+
+fn metadata_values(metadata: Metadata) -> TransportValue {
+    let mut input = TransportValue::new("MetadataState");
+    input.set_value("mint", Value::from(metadata.mint.to_string()));
+    input.set_value(
+        "update_authority",
+        Value::from(metadata.update_authority.to_string()),
+    );
+    input.set_value("name", Value::from(trim_padding(&metadata.data.name)));
+    input.set_value("symbol", Value::from(trim_padding(&metadata.data.symbol)));
+    input.set_value("uri", Value::from(trim_padding(&metadata.data.uri)));
+    input.set_value(
+        "seller_fee_basis_points",
+        Value::from(metadata.data.seller_fee_basis_points),
+    );
+    set_creator_values(&mut input, metadata.data.creators.as_deref().unwrap_or(&[]));
+    input.set_value(
+        "primary_sale_happened",
+        Value::from(metadata.primary_sale_happened),
+    );
+    input.set_value("is_mutable", Value::from(metadata.is_mutable));
+    input.set_value("edition_nonce", Value::from(metadata.edition_nonce));
+    input.set_value(
+        "collection_key",
+        Value::from(
+            metadata
+                .collection
+                .as_ref()
+                .map(|collection| collection.key.to_string()),
+        ),
+    );
+    input.set_value(
+        "collection_verified",
+        Value::from(
+            metadata
+                .collection
+                .as_ref()
+                .map(|collection| collection.verified),
+        ),
+    );
+    input
+}
+
+fn master_edition_values(master_edition: MasterEditionV2) -> TransportValue {
+    let mut input = TransportValue::new("MasterEditionState");
+    input.set_value("supply", Value::from(master_edition.supply));
+    input.set_value("max_supply", Value::from(master_edition.max_supply));
+    input
+}
+
+fn edition_values(edition: Edition) -> TransportValue {
+    let mut input = TransportValue::new("EditionState");
+    input.set_value("parent", Value::from(edition.parent.to_string()));
+    input.set_value("edition", Value::from(edition.edition));
+    input
+}
+
+fn set_creator_values(input: &mut TransportValue, creators: &[Creator]) {
+    input.set_value(
+        "creator_addresses",
+        Value::List(
+            creators
+                .iter()
+                .map(|creator| Value::from(creator.address.to_string()))
+                .collect(),
+        ),
+    );
+    input.set_value(
+        "creator_verified",
+        Value::List(
+            creators
+                .iter()
+                .map(|creator| Value::from(creator.verified))
+                .collect(),
+        ),
+    );
+    input.set_value(
+        "creator_shares",
+        Value::List(
+            creators
+                .iter()
+                .map(|creator| Value::from(creator.share))
+                .collect(),
+        ),
+    );
+}
+
+/// Strings are stored padded with zeros up to their maximum length
+fn trim_padding(value: &str) -> String {
+    value.trim_end_matches(char::from(0)).to_string()
+}
@@ -0,0 +1,35 @@
+use indexer_common::account_keys::AccountKeys;
+use solana_program::instruction::CompiledInstruction;
+use solana_program::pubkey::Pubkey;
+
+mod account_keys {
+    use super::*;
+
+    #[test]
+    fn resolve_loaded_addresses_after_static_keys() {
+        let static_keys = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let writable = vec![Pubkey::new_unique()];
+        let readonly = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let account_keys = AccountKeys::new(&static_keys, &writable, &readonly);
+        assert_eq!(account_keys.len(), 5);
+        assert_eq!(account_keys.get(1), Some(&static_keys[1]));
+        assert_eq!(account_keys.get(2), Some(&writable[0]));
+        assert_eq!(account_keys.get(4), Some(&readonly[1]));
+        assert_eq!(account_keys.get(5), None);
+
+        let instruction = CompiledInstruction::new_from_raw_parts(4, vec![], vec![0, 2, 3]);
+        assert_eq!(account_keys.program_id(&instruction), Some(&readonly[1]));
+        assert_eq!(
+            account_keys.instruction_accounts(&instruction),
+            Ok(vec![static_keys[0], writable[0], readonly[0]])
+        );
+    }
+
+    #[test]
+    fn report_unresolved_account_index() {
+        let static_keys = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let account_keys = AccountKeys::new(&static_keys, &[], &[]);
+        let instruction = CompiledInstruction::new_from_raw_parts(1, vec![], vec![0, 7]);
+        assert_eq!(account_keys.instruction_accounts(&instruction), Err(7));
+    }
+}
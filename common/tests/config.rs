@@ -0,0 +1,14 @@
+use indexer_common::config::ErrorPolicy;
+use std::env;
+
+mod config {
+    use super::*;
+
+    #[test]
+    fn continue_on_decode_errors_by_default() {
+        env::remove_var("ERROR_POLICY");
+        assert_eq!(ErrorPolicy::from_env(), ErrorPolicy::Continue);
+        assert_eq!(ErrorPolicy::parse("fail_fast"), Some(ErrorPolicy::FailFast));
+        assert_eq!(ErrorPolicy::parse("retry"), None);
+    }
+}
@@ -0,0 +1,23 @@
+use indexer_common::context::EntityBatch;
+use massbit_solana_sdk::transport::TransportValue;
+
+mod context {
+    use super::*;
+
+    #[test]
+    fn batch_keeps_save_order() {
+        let batch = EntityBatch::default();
+        for name in ["CreateMetadataAccount", "SignMetadata", "Transaction"] {
+            batch.push(TransportValue::new(name));
+        }
+        let names = batch
+            .into_values()
+            .into_iter()
+            .map(|value| value.name)
+            .collect::<Vec<String>>();
+        assert_eq!(
+            names,
+            vec!["CreateMetadataAccount", "SignMetadata", "Transaction"]
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use indexer_common::enrichment::Enrichment;
+use serde_json::{json, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/// Slot of the `getMultipleAccounts` responses
+const CONTEXT_SLOT: u64 = 120042;
+
+/// Local JSON-RPC server answering `getMultipleAccounts` with one account per requested key,
+/// except `missing` which does not exist and `program` which is executable
+struct MockRpc {
+    url: String,
+    requests: Arc<AtomicUsize>,
+}
+
+impl MockRpc {
+    fn start(missing: Pubkey, program: Pubkey) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let counter = counter.clone();
+                let stream = stream.unwrap();
+                thread::spawn(move || serve(stream, missing, program, counter));
+            }
+        });
+        MockRpc { url, requests }
+    }
+    fn client(&self) -> Arc<RpcClient> {
+        Arc::new(RpcClient::new(self.url.clone()))
+    }
+    /// Number of `getMultipleAccounts` calls received
+    fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+fn serve(stream: TcpStream, missing: Pubkey, program: Pubkey, requests: Arc<AtomicUsize>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    // Requests may share a keep-alive connection
+    loop {
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse::<usize>().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        let request = serde_json::from_slice::<Value>(&body).unwrap();
+        let result = match request["method"].as_str() {
+            Some("getMultipleAccounts") => {
+                requests.fetch_add(1, Ordering::SeqCst);
+                let (missing, program) = (missing.to_string(), program.to_string());
+                let accounts = request["params"][0]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|key| match key.as_str() {
+                        Some(key) if key == missing => Value::Null,
+                        Some(key) if key == program => json!({
+                            "data": ["", "base64"],
+                            "executable": true,
+                            "lamports": 1000,
+                            "owner": "BPFLoaderUpgradeab1e11111111111111111111111",
+                            "rentEpoch": 0
+                        }),
+                        _ => json!({
+                            "data": ["AQID", "base64"],
+                            "executable": false,
+                            "lamports": 1000,
+                            "owner": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+                            "rentEpoch": 0
+                        }),
+                    })
+                    .collect::<Vec<Value>>();
+                json!({ "context": { "slot": CONTEXT_SLOT }, "value": accounts })
+            }
+            _ => json!({ "solana-core": "1.9.0", "feature-set": 0 }),
+        };
+        let response =
+            json!({ "jsonrpc": "2.0", "result": result, "id": request["id"] }).to_string();
+        write!(
+            writer,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        )
+        .unwrap();
+    }
+}
+
+mod enrichment {
+    use super::*;
+
+    #[test]
+    fn fetch_accounts_on_every_call() {
+        let missing = Pubkey::new_unique();
+        let rpc = MockRpc::start(missing, Pubkey::new_unique());
+        let enrichment = Enrichment::new(rpc.client(), 100);
+        let existing = Pubkey::new_unique();
+        let keys = vec![existing, missing]
+            .into_iter()
+            .collect::<HashSet<Pubkey>>();
+
+        let states = enrichment.prefetch(&keys).unwrap();
+        assert_eq!(rpc.requests(), 1);
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[&existing].account.lamports, 1000);
+        assert_eq!(states[&existing].account.data, vec![1, 2, 3]);
+        // Stamped with the slot of the response, not the one of the indexed block
+        assert_eq!(states[&existing].slot, CONTEXT_SLOT);
+
+        // Account data can change between blocks, only immutable accounts are cached
+        let states = enrichment.prefetch(&keys).unwrap();
+        assert_eq!(rpc.requests(), 2);
+        assert_eq!(states.len(), 1);
+    }
+
+    #[test]
+    fn split_large_batches() {
+        let rpc = MockRpc::start(Pubkey::default(), Pubkey::default());
+        let enrichment = Enrichment::new(rpc.client(), 100);
+        let keys = (0..150)
+            .map(|_| Pubkey::new_unique())
+            .collect::<HashSet<Pubkey>>();
+        let states = enrichment.prefetch(&keys).unwrap();
+        assert_eq!(rpc.requests(), 2);
+        assert_eq!(states.len(), 150);
+    }
+
+    #[test]
+    fn cache_executable_accounts() {
+        let program = Pubkey::new_unique();
+        let rpc = MockRpc::start(Pubkey::default(), program);
+        let enrichment = Enrichment::new(rpc.client(), 100);
+        let keys = vec![program].into_iter().collect::<HashSet<Pubkey>>();
+        let states = enrichment.prefetch(&keys).unwrap();
+        assert!(states[&program].account.executable);
+        assert_eq!(rpc.requests(), 1);
+
+        let states = enrichment.prefetch(&keys).unwrap();
+        assert_eq!(rpc.requests(), 1);
+        assert_eq!(states[&program].slot, CONTEXT_SLOT);
+        // Other accounts are still fetched
+        let metadata = Pubkey::new_unique();
+        let keys = vec![program, metadata]
+            .into_iter()
+            .collect::<HashSet<Pubkey>>();
+        let states = enrichment.prefetch(&keys).unwrap();
+        assert_eq!(rpc.requests(), 2);
+        assert_eq!(states.len(), 2);
+    }
+}
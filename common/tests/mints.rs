@@ -0,0 +1,240 @@
+use indexer_common::enrichment::{AccountState, AccountStates};
+use indexer_common::local_state::LocalState;
+use indexer_common::token_metadata::mints::MintIndex;
+use indexer_common::token_metadata::nft::NFT_ENTITY;
+use massbit_solana_sdk::transport::{TransportValue, Value};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use std::fs;
+use std::str::FromStr;
+
+fn program_id() -> Pubkey {
+    Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s").unwrap()
+}
+
+/// Data of a `Metadata` account: key, update authority and mint
+fn metadata_data(mint: &Pubkey) -> Vec<u8> {
+    let mut data = vec![4];
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(mint.as_ref());
+    data
+}
+
+/// Instruction entity as saved by the handler, `mint` is null without a mint account
+fn instruction(name: &str, roles: &[(&str, &Pubkey)], mint: Option<&Pubkey>) -> TransportValue {
+    let mut value = TransportValue::new(name);
+    value.set_value("id", Value::from(format!("sig-{}", name)));
+    for (role, account) in roles {
+        value.set_value(role, Value::from(account.to_string()));
+    }
+    value.set_value("mint", Value::from(mint.map(|mint| mint.to_string())));
+    value.set_value("success", Value::from(true));
+    value.set_value("pda_mismatches", Value::List(Vec::new()));
+    value
+}
+
+fn mint_index() -> MintIndex {
+    MintIndex::new(&LocalState::temporary().unwrap()).unwrap()
+}
+
+fn nft_rows(values: &[TransportValue]) -> usize {
+    values
+        .iter()
+        .filter(|value| value.name == NFT_ENTITY)
+        .count()
+}
+
+mod mints {
+    use super::*;
+
+    #[test]
+    fn index_derived_metadata_of_create() {
+        let mints = mint_index();
+        let (metadata, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let roles = [("metadata", &metadata), ("mint", &mint)];
+        mints.join(
+            vec![instruction("CreateMetadataAccountV2", &roles, Some(&mint))],
+            120,
+        );
+        assert_eq!(mints.get(&metadata), Some(mint));
+    }
+
+    #[test]
+    fn skip_metadata_not_derived_from_mint() {
+        let mints = mint_index();
+        let (metadata, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let roles = [("metadata", &metadata), ("mint", &mint)];
+        let mut value = instruction("CreateMetadataAccountV2", &roles, Some(&mint));
+        value.set_value("pda_mismatches", Value::List(vec![Value::from("metadata")]));
+        mints.join(vec![value], 120);
+        assert_eq!(mints.get(&metadata), None);
+    }
+
+    #[test]
+    fn index_new_metadata_of_mint_edition() {
+        let mints = mint_index();
+        let (new_metadata, new_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let master_metadata = Pubkey::new_unique();
+        let roles = [
+            ("new_metadata", &new_metadata),
+            ("new_mint", &new_mint),
+            ("metadata", &master_metadata),
+        ];
+        mints.join(
+            vec![instruction(
+                "MintNewEditionFromMasterEditionViaToken",
+                &roles,
+                Some(&new_mint),
+            )],
+            120,
+        );
+        assert_eq!(mints.get(&new_metadata), Some(new_mint));
+        assert_eq!(mints.get(&master_metadata), None);
+    }
+
+    #[test]
+    fn join_through_metadata_created_earlier() {
+        let mints = mint_index();
+        let (metadata, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let create = instruction(
+            "CreateMetadataAccountV2",
+            &[("metadata", &metadata), ("mint", &mint)],
+            Some(&mint),
+        );
+        let sign = instruction("SignMetadata", &[("metadata", &metadata)], None);
+        let joined = mints.join(vec![create, sign, TransportValue::new("Transaction")], 120);
+        let sign = joined
+            .iter()
+            .find(|value| value.name == "SignMetadata")
+            .unwrap();
+        assert_eq!(
+            sign.values.get("mint"),
+            Some(&Value::from(mint.to_string()))
+        );
+        // Each instruction is followed by the row of its token, other entities are kept
+        assert_eq!(nft_rows(&joined), 2);
+        assert_eq!(joined.last().unwrap().name, "Transaction");
+    }
+
+    #[test]
+    fn leave_unknown_metadata_unjoined() {
+        let mints = mint_index();
+        let metadata = Pubkey::new_unique();
+        let sign = instruction("SignMetadata", &[("metadata", &metadata)], None);
+        let joined = mints.join(vec![sign], 120);
+        assert_eq!(joined[0].values.get("mint"), Some(&Value::Null));
+        assert_eq!(nft_rows(&joined), 0);
+    }
+
+    #[test]
+    fn skip_rows_of_failed_transactions() {
+        let mints = mint_index();
+        let (metadata, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut create = instruction(
+            "CreateMetadataAccountV2",
+            &[("metadata", &metadata), ("mint", &mint)],
+            Some(&mint),
+        );
+        create.set_value("success", Value::from(false));
+        let joined = mints.join(vec![create], 120);
+        assert_eq!(joined.len(), 1);
+        // The metadata address is derived from the mint whatever the status
+        assert_eq!(mints.get(&metadata), Some(mint));
+    }
+
+    #[test]
+    fn index_edition_of_master_edition() {
+        let mints = mint_index();
+        let (edition, metadata, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let roles = [
+            ("edition", &edition),
+            ("metadata", &metadata),
+            ("mint", &mint),
+        ];
+        mints.join(
+            vec![instruction("CreateMasterEditionV3", &roles, Some(&mint))],
+            120,
+        );
+        assert_eq!(mints.get(&edition), Some(mint));
+        assert_eq!(mints.get(&metadata), Some(mint));
+    }
+
+    #[test]
+    fn join_through_master_edition() {
+        let mints = mint_index();
+        let (edition, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        mints.insert(edition, mint);
+        let convert = instruction(
+            "ConvertMasterEditionV1ToV2",
+            &[("master_edition", &edition)],
+            None,
+        );
+        let joined = mints.join(vec![convert], 120);
+        assert_eq!(
+            joined[0].values.get("mint"),
+            Some(&Value::from(mint.to_string()))
+        );
+    }
+
+    #[test]
+    fn set_mint_of_edition_states() {
+        let mints = mint_index();
+        let (edition, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        mints.insert(edition, mint);
+        let mut master = TransportValue::new("MasterEditionState");
+        mints.set_state_mint(&edition, &mut master);
+        assert_eq!(
+            master.values.get("mint"),
+            Some(&Value::from(mint.to_string()))
+        );
+        let mut unknown = TransportValue::new("EditionState");
+        mints.set_state_mint(&Pubkey::new_unique(), &mut unknown);
+        assert_eq!(unknown.values.get("mint"), Some(&Value::Null));
+        // Metadata states store their mint
+        let mut metadata = TransportValue::new("MetadataState");
+        mints.set_state_mint(&edition, &mut metadata);
+        assert_eq!(metadata.values.get("mint"), None);
+    }
+
+    #[test]
+    fn keep_index_across_restarts() {
+        let path = std::env::temp_dir().join(format!("mint-index-{}", Pubkey::new_unique()));
+        let (metadata, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        {
+            let state = LocalState::open(&path, 1024 * 1024).unwrap();
+            MintIndex::new(&state).unwrap().insert(metadata, mint);
+            state.flush().unwrap();
+        }
+        let state = LocalState::open(&path, 1024 * 1024).unwrap();
+        assert_eq!(MintIndex::new(&state).unwrap().get(&metadata), Some(mint));
+        drop(state);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn index_enriched_metadata_accounts() {
+        let mints = mint_index();
+        let mint = Pubkey::new_unique();
+        let metadata = Pubkey::new_unique();
+        let foreign = Pubkey::new_unique();
+        let mut accounts = AccountStates::new();
+        for (key, owner) in [(metadata, program_id()), (foreign, Pubkey::new_unique())] {
+            let account = Account {
+                lamports: 1000,
+                data: metadata_data(&mint),
+                owner,
+                executable: false,
+                rent_epoch: 0,
+            };
+            accounts.insert(key, AccountState { account, slot: 1 });
+        }
+        mints.index_accounts(&program_id(), &accounts);
+        assert_eq!(mints.get(&metadata), Some(mint));
+        // Data of an account of another program is not a metadata account
+        assert_eq!(mints.get(&foreign), None);
+    }
+}
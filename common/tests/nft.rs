@@ -0,0 +1,153 @@
+use indexer_common::token_metadata::nft::{
+    creator_values, nft_value, CREATOR_ENTITY, CREATOR_VERIFICATION_ENTITY, NFT_CREATOR_ENTITY,
+    NFT_ENTITY,
+};
+use massbit_solana_sdk::transport::{TransportValue, Value};
+
+const MINT: &str = "7JcZPXCNfWvmNbMYXUA4FhhhXVaRk3qc6n5Vt3cN7gfW";
+
+fn entities<'a>(values: &'a [TransportValue], name: &str) -> Vec<&'a TransportValue> {
+    values.iter().filter(|value| value.name == name).collect()
+}
+
+mod nft {
+    use super::*;
+
+    #[test]
+    fn create_sets_created_at_and_data() {
+        let mut input = TransportValue::new("CreateMetadataAccountV2");
+        input.set_value("name", Value::from("Degen #1"));
+        input.set_value("is_mutable", Value::from(true));
+        input.set_value("collection_key", Value::Null);
+        let nft = nft_value(&input, MINT, 120).unwrap();
+        assert_eq!(nft.name, NFT_ENTITY);
+        assert_eq!(nft.values.get("id"), Some(&Value::from(MINT)));
+        assert_eq!(nft.values.get("name"), Some(&Value::from("Degen #1")));
+        assert_eq!(nft.values.get("is_mutable"), Some(&Value::from(true)));
+        assert_eq!(
+            nft.values.get("primary_sale_happened"),
+            Some(&Value::from(false))
+        );
+        assert_eq!(nft.values.get("created_at"), Some(&Value::from(120_u64)));
+        assert_eq!(nft.values.get("updated_at"), Some(&Value::from(120_u64)));
+        assert_eq!(nft.values.get("collection_key"), None);
+    }
+
+    #[test]
+    fn update_only_sets_present_fields() {
+        let mut input = TransportValue::new("UpdateMetadataAccount");
+        input.set_value("update_authority", Value::Null);
+        input.set_value("primary_sale_happened", Value::from(true));
+        let nft = nft_value(&input, MINT, 130).unwrap();
+        assert_eq!(nft.values.get("name"), None);
+        assert_eq!(nft.values.get("update_authority"), None);
+        assert_eq!(nft.values.get("created_at"), None);
+        assert_eq!(
+            nft.values.get("primary_sale_happened"),
+            Some(&Value::from(true))
+        );
+        assert_eq!(nft.values.get("updated_at"), Some(&Value::from(130_u64)));
+    }
+
+    #[test]
+    fn collection_verification() {
+        let mut input = TransportValue::new("VerifyCollection");
+        input.set_value("collection_mint", Value::from("collection"));
+        let nft = nft_value(&input, MINT, 140).unwrap();
+        assert_eq!(
+            nft.values.get("collection_key"),
+            Some(&Value::from("collection"))
+        );
+        assert_eq!(
+            nft.values.get("collection_verified"),
+            Some(&Value::from(true))
+        );
+        let nft = nft_value(&TransportValue::new("UnverifyCollection"), MINT, 150).unwrap();
+        assert_eq!(nft.values.get("collection_key"), None);
+        assert_eq!(
+            nft.values.get("collection_verified"),
+            Some(&Value::from(false))
+        );
+    }
+
+    #[test]
+    fn unrelated_instruction_is_skipped() {
+        assert!(nft_value(&TransportValue::new("Utilize"), MINT, 160).is_none());
+    }
+
+    #[test]
+    fn create_creators() {
+        let mut input = TransportValue::new("CreateMetadataAccount");
+        input.set_value("id", Value::from("sig-0"));
+        input.set_value(
+            "creator_addresses",
+            Value::List(vec![Value::from("alice"), Value::from("bob")]),
+        );
+        input.set_value(
+            "creator_verified",
+            Value::List(vec![Value::from(true), Value::from(false)]),
+        );
+        input.set_value(
+            "creator_shares",
+            Value::List(vec![Value::from(60_u8), Value::from(40_u8)]),
+        );
+        let values = creator_values(&input, MINT, 120);
+        assert_eq!(entities(&values, CREATOR_ENTITY).len(), 2);
+        let nft_creators = entities(&values, NFT_CREATOR_ENTITY);
+        assert_eq!(
+            nft_creators[1].values.get("id"),
+            Some(&Value::from(format!("{}-bob", MINT)))
+        );
+        assert_eq!(
+            nft_creators[1].values.get("share"),
+            Some(&Value::from(40_u8))
+        );
+        assert_eq!(
+            nft_creators[1].values.get("verified"),
+            Some(&Value::from(false))
+        );
+        // Only the creator verified at creation has a history row
+        let history = entities(&values, CREATOR_VERIFICATION_ENTITY);
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0].values.get("id"),
+            Some(&Value::from("sig-0-alice"))
+        );
+    }
+
+    #[test]
+    fn sign_metadata_verifies_signer() {
+        let mut input = TransportValue::new("SignMetadata");
+        input.set_value("id", Value::from("sig-1"));
+        input.set_value("creator", Value::from("bob"));
+        let values = creator_values(&input, MINT, 130);
+        let nft_creators = entities(&values, NFT_CREATOR_ENTITY);
+        assert_eq!(nft_creators.len(), 1);
+        assert_eq!(nft_creators[0].values.get("share"), None);
+        assert_eq!(
+            nft_creators[0].values.get("verified"),
+            Some(&Value::from(true))
+        );
+        let history = entities(&values, CREATOR_VERIFICATION_ENTITY);
+        assert_eq!(history[0].values.get("creator"), Some(&Value::from("bob")));
+        assert_eq!(
+            history[0].values.get("instruction"),
+            Some(&Value::from("SignMetadata"))
+        );
+    }
+
+    #[test]
+    fn update_records_unverified_creators() {
+        let mut input = TransportValue::new("UpdateMetadataAccountV2");
+        input.set_value("creator_addresses", Value::List(vec![Value::from("bob")]));
+        input.set_value("creator_verified", Value::List(vec![Value::from(false)]));
+        input.set_value("creator_shares", Value::List(vec![Value::from(100_u8)]));
+        let values = creator_values(&input, MINT, 140);
+        let history = entities(&values, CREATOR_VERIFICATION_ENTITY);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].values.get("verified"), Some(&Value::from(false)));
+        // Updates without data do not touch the creators
+        let input = TransportValue::new("UpdateMetadataAccount");
+        assert!(creator_values(&input, MINT, 150).is_empty());
+    }
+}
@@ -0,0 +1,68 @@
+use indexer_common::token_metadata::accounts::account_layout;
+use indexer_common::token_metadata::pda::{
+    find_edition_address, find_metadata_address, metadata_mint, mint_role, pda_layout, PdaKind,
+};
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+const INSTRUCTIONS: [&str; 12] = [
+    "CreateMetadataAccount",
+    "CreateMetadataAccountV2",
+    "DeprecatedCreateMasterEdition",
+    "CreateMasterEditionV3",
+    "DeprecatedMintNewEditionFromMasterEditionViaPrintingToken",
+    "MintNewEditionFromMasterEditionViaToken",
+    "MintNewEditionFromMasterEditionViaVaultProxy",
+    "VerifyCollection",
+    "UnverifyCollection",
+    "Utilize",
+    "ApproveCollectionAuthority",
+    "RevokeUseAuthority",
+];
+
+mod pda {
+    use super::*;
+
+    #[test]
+    fn pda_roles_are_in_account_layout() {
+        for name in INSTRUCTIONS.iter() {
+            let roles = account_layout(name);
+            for (role, mint, _) in pda_layout(name) {
+                assert!(roles.contains(role), "{} has no {} account", name, role);
+                assert!(roles.contains(mint), "{} has no {} account", name, mint);
+            }
+            if let Some(mint) = mint_role(name) {
+                assert!(roles.contains(&mint), "{} has no {} account", name, mint);
+            }
+        }
+    }
+
+    #[test]
+    fn derive_metadata_and_edition() {
+        let program_id = Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s").unwrap();
+        let mint = Pubkey::new_unique();
+        let metadata = find_metadata_address(&program_id, &mint);
+        let (expected, _) = Pubkey::find_program_address(
+            &["metadata".as_bytes(), program_id.as_ref(), mint.as_ref()],
+            &program_id,
+        );
+        assert_eq!(metadata, expected);
+        assert_eq!(PdaKind::Metadata.find_address(&program_id, &mint), metadata);
+        let edition = find_edition_address(&program_id, &mint);
+        assert_ne!(edition, metadata);
+        assert_eq!(PdaKind::Edition.find_address(&program_id, &mint), edition);
+    }
+
+    #[test]
+    fn read_mint_from_metadata_data() {
+        let mint = Pubkey::new_unique();
+        let mut data = vec![4];
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(mint.as_ref());
+        data.resize(679, 0);
+        assert_eq!(metadata_mint(&data), Some(mint));
+        data[0] = 6;
+        assert_eq!(metadata_mint(&data), None);
+        assert_eq!(metadata_mint(&[4, 0]), None);
+    }
+}
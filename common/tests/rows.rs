@@ -0,0 +1,222 @@
+use indexer_common::local_state::LocalState;
+use indexer_common::token_metadata::nft::{
+    creator_values, nft_value, CREATOR_ENTITY, CREATOR_VERIFICATION_ENTITY, NFT_CREATOR_ENTITY,
+    NFT_ENTITY,
+};
+use indexer_common::token_metadata::rows::NftRows;
+use massbit_solana_sdk::transport::{TransportValue, Value};
+use solana_program::pubkey::Pubkey;
+use std::fs;
+
+const MINT: &str = "7JcZPXCNfWvmNbMYXUA4FhhhXVaRk3qc6n5Vt3cN7gfW";
+
+fn nft_rows() -> NftRows {
+    NftRows::new(&LocalState::temporary().unwrap()).unwrap()
+}
+
+fn create(metadata: &str, creator: &str) -> TransportValue {
+    let mut input = TransportValue::new("CreateMetadataAccountV2");
+    input.set_value("id", Value::from("sig-0"));
+    input.set_value("metadata", Value::from(metadata));
+    input.set_value("name", Value::from("Degen #1"));
+    input.set_value(
+        "creator_addresses",
+        Value::List(vec![Value::from("other"), Value::from(creator)]),
+    );
+    input.set_value(
+        "creator_verified",
+        Value::List(vec![Value::from(true), Value::from(false)]),
+    );
+    input.set_value(
+        "creator_shares",
+        Value::List(vec![Value::from(60_u8), Value::from(40_u8)]),
+    );
+    input
+}
+
+fn update(metadata: &str, creator: &str, verified: bool) -> TransportValue {
+    let mut input = create(metadata, creator);
+    input.name = String::from("UpdateMetadataAccountV2");
+    input.set_value("id", Value::from("sig-2"));
+    input.set_value(
+        "creator_verified",
+        Value::List(vec![Value::from(true), Value::from(verified)]),
+    );
+    input
+}
+
+fn sign(metadata: &str, creator: &str) -> TransportValue {
+    let mut input = TransportValue::new("SignMetadata");
+    input.set_value("id", Value::from("sig-1"));
+    input.set_value("metadata", Value::from(metadata));
+    input.set_value("creator", Value::from(creator));
+    input
+}
+
+/// Entities an instruction saves for its token
+fn saved(input: &TransportValue, slot: u64) -> Vec<TransportValue> {
+    let mut values = nft_value(input, MINT, slot).into_iter().collect::<Vec<_>>();
+    values.extend(creator_values(input, MINT, slot));
+    values
+}
+
+fn entity<'a>(values: &'a [TransportValue], name: &str) -> Option<&'a TransportValue> {
+    values.iter().find(|value| value.name == name)
+}
+
+mod rows {
+    use super::*;
+
+    #[test]
+    fn write_partial_rows() {
+        let rows = nft_rows();
+        let metadata = Pubkey::new_unique().to_string();
+        rows.merge(saved(&create(&metadata, "creator"), 120));
+
+        let merged = rows.merge(saved(&sign(&metadata, "creator"), 130));
+        // The store merges the set fields into the saved entities
+        let nft = entity(&merged, NFT_ENTITY).unwrap();
+        assert_eq!(nft.values.get("name"), None);
+        assert_eq!(nft.values.get("created_at"), None);
+        assert_eq!(nft.values.get("updated_at"), Some(&Value::from(130_u64)));
+        let nft_creator = entity(&merged, NFT_CREATOR_ENTITY).unwrap();
+        assert_eq!(nft_creator.values.get("position"), None);
+        assert_eq!(nft_creator.values.get("verified"), Some(&Value::from(true)));
+        // Other entities are written as they are
+        assert!(entity(&merged, CREATOR_ENTITY).is_some());
+    }
+
+    #[test]
+    fn write_updates_of_unknown_tokens() {
+        let rows = nft_rows();
+        let metadata = Pubkey::new_unique().to_string();
+        let merged = rows.merge(saved(&sign(&metadata, "creator"), 130));
+        let nft = entity(&merged, NFT_ENTITY).unwrap();
+        // Without the creators of the token its list cannot be updated
+        assert_eq!(nft.values.get("creator_verified"), None);
+        assert!(entity(&merged, NFT_CREATOR_ENTITY).is_some());
+        assert!(entity(&merged, CREATOR_VERIFICATION_ENTITY).is_some());
+    }
+
+    #[test]
+    fn sign_updates_creator_verified_of_nft() {
+        let rows = nft_rows();
+        let metadata = Pubkey::new_unique().to_string();
+        rows.merge(saved(&create(&metadata, "creator"), 120));
+
+        let merged = rows.merge(saved(&sign(&metadata, "creator"), 130));
+        let nft = entity(&merged, NFT_ENTITY).unwrap();
+        assert_eq!(
+            nft.values.get("creator_verified"),
+            Some(&Value::List(vec![Value::from(true), Value::from(true)]))
+        );
+        assert!(entity(&merged, CREATOR_VERIFICATION_ENTITY).is_some());
+        // Signing again does not change the status
+        let merged = rows.merge(saved(&sign(&metadata, "creator"), 140));
+        assert!(entity(&merged, CREATOR_VERIFICATION_ENTITY).is_none());
+    }
+
+    #[test]
+    fn record_history_only_on_change() {
+        let rows = nft_rows();
+        let metadata = Pubkey::new_unique().to_string();
+        let merged = rows.merge(saved(&create(&metadata, "creator"), 120));
+        // Only the verified creator gets a history row at creation
+        assert_eq!(
+            merged
+                .iter()
+                .filter(|value| value.name == CREATOR_VERIFICATION_ENTITY)
+                .count(),
+            1
+        );
+
+        let input = update(&metadata, "creator", false);
+        let merged = rows.merge(saved(&input, 130));
+        assert!(entity(&merged, CREATOR_VERIFICATION_ENTITY).is_none());
+
+        let input = update(&metadata, "creator", true);
+        let merged = rows.merge(saved(&input, 140));
+        let history = merged
+            .iter()
+            .filter(|value| value.name == CREATOR_VERIFICATION_ENTITY)
+            .collect::<Vec<_>>();
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0].values.get("creator"),
+            Some(&Value::from("creator"))
+        );
+        assert_eq!(history[0].values.get("verified"), Some(&Value::from(true)));
+    }
+
+    #[test]
+    fn deactivate_removed_creators() {
+        let rows = nft_rows();
+        let metadata = Pubkey::new_unique().to_string();
+        rows.merge(saved(&create(&metadata, "creator"), 120));
+
+        let mut input = update(&metadata, "creator", false);
+        input.set_value("creator_addresses", Value::List(vec![Value::from("other")]));
+        input.set_value("creator_verified", Value::List(vec![Value::from(true)]));
+        input.set_value("creator_shares", Value::List(vec![Value::from(100_u8)]));
+        let merged = rows.merge(saved(&input, 130));
+        let nft_creators = merged
+            .iter()
+            .filter(|value| value.name == NFT_CREATOR_ENTITY)
+            .collect::<Vec<_>>();
+        assert_eq!(nft_creators.len(), 2);
+        let removed = nft_creators
+            .iter()
+            .find(|value| value.values.get("creator") == Some(&Value::from("creator")))
+            .unwrap();
+        assert_eq!(removed.values.get("active"), Some(&Value::from(false)));
+        assert_eq!(removed.values.get("position"), Some(&Value::Null));
+        assert_eq!(
+            removed.values.get("updated_at"),
+            Some(&Value::from(130_u64))
+        );
+        let kept = nft_creators
+            .iter()
+            .find(|value| value.values.get("creator") == Some(&Value::from("other")))
+            .unwrap();
+        assert_eq!(kept.values.get("active"), Some(&Value::from(true)));
+        assert_eq!(kept.values.get("position"), Some(&Value::from(0_u64)));
+    }
+
+    #[test]
+    fn keep_committed_creators_across_restarts() {
+        let path = std::env::temp_dir().join(format!("nft-rows-{}", Pubkey::new_unique()));
+        let metadata = Pubkey::new_unique().to_string();
+        {
+            let state = LocalState::open(&path, 1024 * 1024).unwrap();
+            let rows = NftRows::new(&state).unwrap();
+            rows.merge(saved(&create(&metadata, "creator"), 120));
+            rows.commit().unwrap();
+            state.flush().unwrap();
+        }
+        let state = LocalState::open(&path, 1024 * 1024).unwrap();
+        let rows = NftRows::new(&state).unwrap();
+        let merged = rows.merge(saved(&sign(&metadata, "creator"), 130));
+        let nft = entity(&merged, NFT_ENTITY).unwrap();
+        assert_eq!(
+            nft.values.get("creator_verified"),
+            Some(&Value::List(vec![Value::from(true), Value::from(true)]))
+        );
+        drop(rows);
+        drop(state);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn discard_creators_of_unflushed_blocks() {
+        let rows = nft_rows();
+        let metadata = Pubkey::new_unique().to_string();
+        rows.merge(saved(&create(&metadata, "creator"), 120));
+        rows.commit().unwrap();
+        rows.merge(saved(&sign(&metadata, "creator"), 130));
+        rows.discard();
+        // The block is handled again after the failure
+        let merged = rows.merge(saved(&sign(&metadata, "creator"), 130));
+        assert!(entity(&merged, CREATOR_VERIFICATION_ENTITY).is_some());
+        assert_eq!(rows.creators(MINT).unwrap().verified, vec![true, true]);
+    }
+}
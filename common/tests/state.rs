@@ -0,0 +1,118 @@
+use indexer_common::token_metadata::state::{decode_account, StateSource};
+use massbit_solana_sdk::transport::Value;
+use solana_program::pubkey::Pubkey;
+
+/// Borsh encoded string padded with zeros like the on-chain data
+fn padded_string(value: &str, max_len: usize) -> Vec<u8> {
+    let mut bytes = (max_len as u32).to_le_bytes().to_vec();
+    bytes.extend_from_slice(value.as_bytes());
+    bytes.resize(4 + max_len, 0);
+    bytes
+}
+
+fn metadata_data(mint: &Pubkey, creator: &Pubkey) -> Vec<u8> {
+    let mut data = vec![4];
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(mint.as_ref());
+    data.extend(padded_string("Club Suite #4820", 32));
+    data.extend(padded_string("SUITE", 10));
+    data.extend(padded_string("https://arweave.net/4820.json", 200));
+    data.extend_from_slice(&500_u16.to_le_bytes());
+    // One verified creator with the whole share
+    data.push(1);
+    data.extend_from_slice(&1_u32.to_le_bytes());
+    data.extend_from_slice(creator.as_ref());
+    data.extend_from_slice(&[1, 100]);
+    // Primary sale happened, mutable
+    data.extend_from_slice(&[1, 1]);
+    // Optional fields are left empty and the account is padded to its allocated size
+    data.resize(679, 0);
+    data
+}
+
+mod state {
+    use super::*;
+
+    #[test]
+    fn decode_metadata() {
+        let address = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let value = decode_account(
+            &address,
+            &metadata_data(&mint, &creator),
+            120000,
+            StateSource::Enrichment,
+        )
+        .unwrap();
+        assert_eq!(value.name.as_str(), "MetadataState");
+        assert_eq!(
+            value.values.get("id"),
+            Some(&Value::from(address.to_string()))
+        );
+        assert_eq!(
+            value.values.get("mint"),
+            Some(&Value::from(mint.to_string()))
+        );
+        assert_eq!(
+            value.values.get("name"),
+            Some(&Value::from("Club Suite #4820"))
+        );
+        assert_eq!(
+            value.values.get("seller_fee_basis_points"),
+            Some(&Value::from(500_u16))
+        );
+        assert_eq!(
+            value.values.get("creator_addresses"),
+            Some(&Value::List(vec![Value::from(creator.to_string())]))
+        );
+        assert_eq!(value.values.get("source"), Some(&Value::from("enrichment")));
+    }
+
+    #[test]
+    fn decode_editions() {
+        let mut master_edition = vec![6];
+        master_edition.extend_from_slice(&5_u64.to_le_bytes());
+        master_edition.push(1);
+        master_edition.extend_from_slice(&10_u64.to_le_bytes());
+        let value = decode_account(
+            &Pubkey::new_unique(),
+            &master_edition,
+            1,
+            StateSource::AccountUpdate,
+        )
+        .unwrap();
+        assert_eq!(value.name.as_str(), "MasterEditionState");
+        assert_eq!(value.values.get("supply"), Some(&Value::from(5_u64)));
+        assert_eq!(
+            value.values.get("max_supply"),
+            Some(&Value::from(Some(10_u64)))
+        );
+
+        let parent = Pubkey::new_unique();
+        let mut edition = vec![1];
+        edition.extend_from_slice(parent.as_ref());
+        edition.extend_from_slice(&3_u64.to_le_bytes());
+        let value = decode_account(
+            &Pubkey::new_unique(),
+            &edition,
+            1,
+            StateSource::AccountUpdate,
+        )
+        .unwrap();
+        assert_eq!(value.name.as_str(), "EditionState");
+        assert_eq!(
+            value.values.get("parent"),
+            Some(&Value::from(parent.to_string()))
+        );
+        assert_eq!(value.values.get("edition"), Some(&Value::from(3_u64)));
+    }
+
+    #[test]
+    fn skip_other_accounts() {
+        let address = Pubkey::new_unique();
+        assert!(decode_account(&address, &[], 1, StateSource::Enrichment).is_none());
+        assert!(decode_account(&address, &[7, 0, 0], 1, StateSource::Enrichment).is_none());
+        assert!(decode_account(&address, &[6, 1], 1, StateSource::Enrichment).is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use indexer_common::config::default_rpc_url;
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use solana_program::pubkey::Pubkey;
+use std::env;
+use std::fs;
+use std::str::FromStr;
+
+/// Manifest built into the plugin, used when `SUBGRAPH_MANIFEST` is not set
+const DEFAULT_MANIFEST: &str = include_str!("subgraph.yaml");
+
+lazy_static! {
+    pub static ref INDEXER_CONFIG: IndexerConfig = IndexerConfig::load();
+}
+
+/// Programs the plugin can decode, selected by the `abi` of a data source
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Program {
+    Metaplex,
+    TokenMetadata,
+}
+
+impl Program {
+    pub fn parse(abi: &str) -> Option<Self> {
+        match abi.trim_end_matches(',').to_lowercase().as_str() {
+            "metaplex" | "nft" => Some(Program::Metaplex),
+            "token-metadata" | "token_metadata" => Some(Program::TokenMetadata),
+            _ => None,
+        }
+    }
+    /// Env overriding the address of the program
+    pub fn address_env(&self) -> &'static str {
+        match self {
+            Program::Metaplex => "METAPLEX_PROGRAM_ADDRESS",
+            Program::TokenMetadata => "TOKEN_METADATA_PROGRAM_ADDRESS",
+        }
+    }
+}
+
+/// A program indexed by the plugin, read from one data source of the manifest
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgramSource {
+    pub program: Program,
+    pub address: String,
+    /// `address` parsed once, so the block scan compares bytes instead of base58 strings
+    pub program_id: Pubkey,
+    pub start_block: u64,
+}
+
+/// Program and network settings read from the `subgraph.yaml` manifest.
+/// Each data source adds a program, its address can be overridden by the program env
+/// (`METAPLEX_PROGRAM_ADDRESS`, `TOKEN_METADATA_PROGRAM_ADDRESS`), or by `PROGRAM_ADDRESS` for the first one.
+/// Network settings come from the first data source and can be overridden by the
+/// `SOLANA_NETWORK`, `START_BLOCK` and `SOLANA_RPC_URL` envs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexerConfig {
+    pub programs: Vec<ProgramSource>,
+    pub network: String,
+    /// Lowest start block of the programs
+    pub start_block: u64,
+    pub rpc_url: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Manifest {
+    #[serde(rename = "dataSources", default)]
+    data_sources: Vec<DataSource>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DataSource {
+    network: Option<String>,
+    #[serde(default)]
+    source: Source,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Source {
+    address: Option<String>,
+    abi: Option<String>,
+    start_block: Option<u64>,
+    rpc_url: Option<String>,
+}
+
+impl IndexerConfig {
+    /// Read the manifest from the `SUBGRAPH_MANIFEST` path, or the one built into the plugin
+    pub fn load() -> Self {
+        let content = match env::var("SUBGRAPH_MANIFEST") {
+            Ok(path) => fs::read_to_string(&path).unwrap_or_else(|err| {
+                println!(
+                    "Cannot read manifest {}: {}, use the default one",
+                    path, err
+                );
+                DEFAULT_MANIFEST.to_string()
+            }),
+            Err(_) => DEFAULT_MANIFEST.to_string(),
+        };
+        let config = IndexerConfig::from_manifest(&content);
+        println!("Indexer config {:?}", &config);
+        config
+    }
+    pub fn from_manifest(content: &str) -> Self {
+        let manifest = serde_yaml::from_str::<Manifest>(content).unwrap_or_else(|err| {
+            println!("Cannot parse manifest: {}", err);
+            Manifest::default()
+        });
+        let start_block = env::var("START_BLOCK")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok());
+        let mut programs = Vec::new();
+        for (ind, data_source) in manifest.data_sources.iter().enumerate() {
+            // The first data source is the Metaplex program when no abi is given
+            let program = match data_source.source.abi.as_deref() {
+                Some(abi) => match Program::parse(abi) {
+                    Some(program) => program,
+                    None => {
+                        println!("Unknown abi {}, skip the data source", abi);
+                        continue;
+                    }
+                },
+                None if ind == 0 => Program::Metaplex,
+                None => continue,
+            };
+            let address = env::var(program.address_env())
+                .ok()
+                .or_else(|| match ind {
+                    0 => env::var("PROGRAM_ADDRESS").ok(),
+                    _ => None,
+                })
+                .or_else(|| data_source.source.address.clone())
+                .unwrap_or_default();
+            let program_id = match Pubkey::from_str(&address) {
+                Ok(program_id) => program_id,
+                Err(err) => {
+                    println!(
+                        "Invalid program address {}: {}, skip the data source",
+                        address, err
+                    );
+                    continue;
+                }
+            };
+            programs.push(ProgramSource {
+                program,
+                address,
+                program_id,
+                start_block: start_block
+                    .or(data_source.source.start_block)
+                    .unwrap_or_default(),
+            });
+        }
+        let data_source = manifest.data_sources.into_iter().next().unwrap_or_default();
+        let network = env::var("SOLANA_NETWORK")
+            .ok()
+            .or(data_source.network)
+            .unwrap_or_else(|| String::from("mainnet"));
+        IndexerConfig {
+            start_block: programs
+                .iter()
+                .map(|source| source.start_block)
+                .min()
+                .unwrap_or_default(),
+            programs,
+            rpc_url: env::var("SOLANA_RPC_URL")
+                .ok()
+                .or(data_source.source.rpc_url)
+                .unwrap_or_else(|| default_rpc_url(&network).to_string()),
+            network,
+        }
+    }
+    /// Program indexed with the given id, a linear scan is faster than hashing for a few programs
+    pub fn program(&self, program_id: &Pubkey) -> Option<&ProgramSource> {
+        self.programs
+            .iter()
+            .find(|source| &source.program_id == program_id)
+    }
+}
@@ -0,0 +1,379 @@
+/// Account layout of each instruction, in the order documented by the metaplex program.
+/// Optional trailing accounts are listed too, they are only saved when present.
+pub fn account_layout(instruction_name: &str) -> &'static [&'static str] {
+    match instruction_name {
+        "DeprecatedInitAuctionManagerV1" => &[
+            "auction_manager",
+            "vault",
+            "auction",
+            "auction_manager_authority",
+            "payer",
+            "accept_payment_account",
+            "store",
+            "system_program",
+            "rent",
+        ],
+        "DeprecatedValidateSafetyDepositBoxV1" => &[
+            "safety_deposit_validation_ticket",
+            "auction_manager",
+            "metadata",
+            "original_authority_lookup",
+            "whitelisted_creator",
+            "store",
+            "safety_deposit_box",
+            "safety_deposit_token_store",
+            "mint",
+            "edition_record",
+            "vault",
+            "authority",
+            "metadata_authority",
+            "payer",
+            "printing_mint",
+            "printing_mint_authority",
+            "token_metadata_program",
+            "system_program",
+            "rent",
+        ],
+        "RedeemBid" => &[
+            "auction_manager",
+            "safety_deposit_token_storage",
+            "destination",
+            "bid_redemption_key",
+            "safety_deposit_box",
+            "vault",
+            "fraction_mint",
+            "auction",
+            "bidder_metadata",
+            "bidder",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "store",
+            "system_program",
+            "rent",
+            "transfer_authority",
+            "safety_deposit_config",
+            "auction_extended",
+        ],
+        "RedeemFullRightsTransferBid" => &[
+            "auction_manager",
+            "safety_deposit_token_storage",
+            "destination",
+            "bid_redemption_key",
+            "safety_deposit_box",
+            "vault",
+            "fraction_mint",
+            "auction",
+            "bidder_metadata",
+            "bidder",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "store",
+            "system_program",
+            "rent",
+            "master_metadata",
+            "new_metadata_authority",
+            "transfer_authority",
+            "safety_deposit_config",
+            "auction_extended",
+        ],
+        // The proxied call decides the trailing accounts, only the shared prefix is named
+        "RedeemUnusedWinningConfigItemsAsAuctioneer" => &[
+            "auction_manager",
+            "safety_deposit_token_storage",
+            "destination",
+            "bid_redemption_key",
+            "safety_deposit_box",
+            "vault",
+            "fraction_mint",
+            "auction",
+            "bidder_metadata",
+            "bidder",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "store",
+            "system_program",
+            "rent",
+        ],
+        "DeprecatedRedeemParticipationBid" => &[
+            "auction_manager",
+            "safety_deposit_token_storage",
+            "destination",
+            "bid_redemption_key",
+            "safety_deposit_box",
+            "vault",
+            "fraction_mint",
+            "auction",
+            "bidder_metadata",
+            "bidder",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "store",
+            "system_program",
+            "rent",
+            "transfer_authority",
+            "accept_payment_account",
+            "bidder_token_account",
+            "participation_printing_holding_account",
+        ],
+        "StartAuction" => &[
+            "auction_manager",
+            "auction",
+            "auction_manager_authority",
+            "store",
+            "auction_program",
+            "clock",
+        ],
+        "ClaimBid" => &[
+            "accept_payment_account",
+            "bidder_pot_token",
+            "bidder_pot",
+            "auction_manager_authority",
+            "auction",
+            "bidder",
+            "token_mint",
+            "vault",
+            "store",
+            "auction_program",
+            "clock",
+            "token_program",
+            "auction_extended",
+        ],
+        "EmptyPaymentAccount" => &[
+            "accept_payment_account",
+            "destination",
+            "auction_manager",
+            "payout_ticket",
+            "payer",
+            "metadata",
+            "master_edition",
+            "safety_deposit_box",
+            "store",
+            "vault",
+            "auction",
+            "token_program",
+            "system_program",
+            "rent",
+            "auction_winner_token_type_tracker",
+            "safety_deposit_config",
+        ],
+        "SetStore" => &[
+            "store",
+            "admin",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "auction_program",
+            "system_program",
+            "rent",
+        ],
+        "SetWhitelistedCreator" => &[
+            "whitelisted_creator",
+            "admin",
+            "payer",
+            "creator",
+            "store",
+            "system_program",
+            "rent",
+        ],
+        "DeprecatedValidateParticipation" => &[
+            "auction_manager",
+            "open_edition_metadata",
+            "open_edition_master_edition",
+            "printing_authorization_token_account",
+            "authority",
+            "whitelisted_creator",
+            "store",
+            "safety_deposit_box",
+            "safety_deposit_token_store",
+            "vault",
+        ],
+        "DeprecatedPopulateParticipationPrintingAccount" => &[
+            "safety_deposit_token_store",
+            "transient_one_time_auth_holding_account",
+            "participation_printing_holding_account",
+            "one_time_printing_authorization_mint",
+            "printing_mint",
+            "participation_master_edition",
+            "participation_metadata",
+            "auction_manager",
+            "safety_deposit_box",
+            "vault",
+            "auction",
+            "store",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "rent",
+        ],
+        "DecommissionAuctionManager" => &[
+            "auction_manager",
+            "auction",
+            "authority",
+            "vault",
+            "store",
+            "auction_program",
+            "clock",
+            "token_vault_program",
+        ],
+        "RedeemPrintingV2Bid" => &[
+            "auction_manager",
+            "safety_deposit_token_storage",
+            "destination",
+            "bid_redemption_key",
+            "safety_deposit_box",
+            "vault",
+            "safety_deposit_config",
+            "auction",
+            "bidder_metadata",
+            "bidder",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "store",
+            "system_program",
+            "rent",
+            "prize_tracking_ticket",
+            "new_metadata",
+            "new_edition",
+            "master_edition",
+            "new_mint",
+            "edition_mark_pda",
+            "mint_authority",
+            "metadata",
+            "auction_extended",
+        ],
+        "WithdrawMasterEdition" => &[
+            "auction_manager",
+            "safety_deposit_token_storage",
+            "destination",
+            "safety_deposit_box",
+            "vault",
+            "fraction_mint",
+            "prize_tracking_ticket",
+            "vault_authority",
+            "auction",
+            "auction_extended",
+            "token_program",
+            "token_vault_program",
+            "store",
+            "rent",
+            "safety_deposit_config",
+        ],
+        "DeprecatedRedeemParticipationBidV2" | "RedeemParticipationBidV3" => &[
+            "auction_manager",
+            "safety_deposit_token_storage",
+            "destination",
+            "bid_redemption_key",
+            "safety_deposit_box",
+            "vault",
+            "safety_deposit_config",
+            "auction",
+            "bidder_metadata",
+            "bidder",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "store",
+            "system_program",
+            "rent",
+            "transfer_authority",
+            "accept_payment_account",
+            "bidder_token_account",
+            "prize_tracking_ticket",
+            "new_metadata",
+            "new_edition",
+            "master_edition",
+            "new_mint",
+            "edition_mark_pda",
+            "mint_authority",
+            "metadata",
+            "auction_extended",
+        ],
+        "InitAuctionManagerV2" => &[
+            "auction_manager",
+            "auction_winner_token_type_tracker",
+            "vault",
+            "auction",
+            "auction_manager_authority",
+            "payer",
+            "accept_payment_account",
+            "store",
+            "system_program",
+            "rent",
+        ],
+        "ValidateSafetyDepositBoxV2" => &[
+            "safety_deposit_config",
+            "auction_winner_token_type_tracker",
+            "auction_manager",
+            "metadata",
+            "original_authority_lookup",
+            "whitelisted_creator",
+            "store",
+            "safety_deposit_box",
+            "safety_deposit_token_store",
+            "mint",
+            "edition_record",
+            "vault",
+            "authority",
+            "metadata_authority",
+            "payer",
+            "token_metadata_program",
+            "system_program",
+            "rent",
+        ],
+        "EndAuction" => &[
+            "auction_manager",
+            "auction",
+            "auction_extended",
+            "auction_manager_authority",
+            "store",
+            "auction_program",
+            "clock",
+        ],
+        "SetStoreIndex" => &[
+            "store_index",
+            "payer",
+            "auction_cache",
+            "store",
+            "system_program",
+            "rent",
+            "above_cache",
+            "below_cache",
+        ],
+        "SetAuctionCache" => &[
+            "auction_cache",
+            "payer",
+            "auction",
+            "safety_deposit_box",
+            "auction_manager",
+            "store",
+            "system_program",
+            "rent",
+            "clock",
+        ],
+        "SetStoreV2" => &[
+            "store",
+            "store_config",
+            "admin",
+            "payer",
+            "token_program",
+            "token_vault_program",
+            "token_metadata_program",
+            "auction_program",
+            "system_program",
+            "rent",
+        ],
+        _ => &[],
+    }
+}
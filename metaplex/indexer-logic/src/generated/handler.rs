@@ -1,4 +1,9 @@
-use crate::STORE;
+use super::accounts::account_layout;
+use indexer_common::context::EntityBatch;
+use indexer_common::enrichment::AccountStates;
+use indexer_common::entity::{set_instruction_values, TransportValueExt};
+use indexer_common::error::IndexerError;
+use indexer_common::position::InstructionPosition;
 use massbit_solana_sdk::entity::{Attribute, Entity, Value};
 use massbit_solana_sdk::{
     transport::{TransportValue, Value as TransValue},
@@ -6,211 +11,184 @@ use massbit_solana_sdk::{
 };
 use serde_json;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use solana_transaction_status::TransactionWithStatusMeta;
 use std::collections::HashMap;
 use uuid::Uuid;
 
-pub trait TransportValueExt {
-    fn save(&self);
+pub struct Handler<'a> {
+    pub batch: &'a EntityBatch,
+    /// State of the accounts referenced in the block, empty unless enrichment is enabled
+    pub accounts: &'a AccountStates,
 }
-impl TransportValueExt for TransportValue {
-    fn save(&self) {
-        unsafe {
-            STORE
-                .as_mut()
-                .unwrap()
-                .save_values(&self.name, &self.values);
-        }
+impl<'a> Handler<'a> {
+    /// State of an account fetched by the enrichment stage, no RPC call is made here
+    pub fn account(&self, key: &Pubkey) -> Option<&Account> {
+        self.accounts.get(key).map(|state| &state.account)
     }
-}
-
-pub struct Handler {}
-impl Handler {
     pub fn process(
         &self,
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
+        position: &InstructionPosition,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         mut input: TransportValue,
-    ) {
+    ) -> Result<(), IndexerError> {
         //println!("Process block {} with input {:?}", block.block_number, input);
+        set_instruction_values(block, transaction, position, &mut input);
+        self.set_account_values(accounts, &mut input);
         match input.name.as_str() {
-            "DeprecatedInitAuctionManagerV1" => {
-                self.process_deprecated_init_auction_manager_v1(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "DeprecatedValidateSafetyDepositBoxV1" => {
-                self.process_deprecated_validate_safety_deposit_box_v1(
+            "DeprecatedInitAuctionManagerV1" => self.process_deprecated_init_auction_manager_v1(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedValidateSafetyDepositBoxV1" => self
+                .process_deprecated_validate_safety_deposit_box_v1(
                     block,
                     transaction,
                     program_id,
                     accounts,
                     &mut input,
-                );
-            }
+                ),
             "RedeemBid" => {
-                self.process_redeem_bid(block, transaction, program_id, accounts, &mut input);
-            }
-            "RedeemFullRightsTransferBid" => {
-                self.process_redeem_full_rights_transfer_bid(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "DeprecatedRedeemParticipationBid" => {
-                self.process_deprecated_redeem_participation_bid(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
+                self.process_redeem_bid(block, transaction, program_id, accounts, &mut input)
             }
+            "RedeemFullRightsTransferBid" => self.process_redeem_full_rights_transfer_bid(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedRedeemParticipationBid" => self.process_deprecated_redeem_participation_bid(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
             "StartAuction" => {
-                self.process_start_auction(block, transaction, program_id, accounts, &mut input);
+                self.process_start_auction(block, transaction, program_id, accounts, &mut input)
             }
             "ClaimBid" => {
-                self.process_claim_bid(block, transaction, program_id, accounts, &mut input);
-            }
-            "EmptyPaymentAccount" => {
-                self.process_empty_payment_account(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
+                self.process_claim_bid(block, transaction, program_id, accounts, &mut input)
             }
+            "EmptyPaymentAccount" => self.process_empty_payment_account(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
             "SetStore" => {
-                self.process_set_store(block, transaction, program_id, accounts, &mut input);
+                self.process_set_store(block, transaction, program_id, accounts, &mut input)
             }
-            "SetWhitelistedCreator" => {
-                self.process_set_whitelisted_creator(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "DeprecatedValidateParticipation" => {
-                self.process_deprecated_validate_participation(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "DeprecatedPopulateParticipationPrintingAccount" => {
-                self.process_deprecated_populate_participation_printing_account(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "RedeemUnusedWinningConfigItemsAsAuctioneer" => {
-                self.process_redeem_unused_winning_config_items_as_auctioneer(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "DecommissionAuctionManager" => {
-                self.process_decommission_auction_manager(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "RedeemPrintingV2Bid" => {
-                self.process_redeem_printing_v2_bid(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "WithdrawMasterEdition" => {
-                self.process_withdraw_master_edition(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "DeprecatedRedeemParticipationBidV2" => {
-                self.process_deprecated_redeem_participation_bid_v2(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
-            }
-            "InitAuctionManagerV2" => {
-                self.process_init_auction_manager_v2(
+            "SetWhitelistedCreator" => self.process_set_whitelisted_creator(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedValidateParticipation" => self.process_deprecated_validate_participation(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedPopulateParticipationPrintingAccount" => self
+                .process_deprecated_populate_participation_printing_account(
                     block,
                     transaction,
                     program_id,
                     accounts,
                     &mut input,
-                );
-            }
-            "ValidateSafetyDepositBoxV2" => {
-                self.process_validate_safety_deposit_box_v2(
+                ),
+            "RedeemUnusedWinningConfigItemsAsAuctioneer" => self
+                .process_redeem_unused_winning_config_items_as_auctioneer(
                     block,
                     transaction,
                     program_id,
                     accounts,
                     &mut input,
-                );
-            }
-            "RedeemParticipationBidV3" => {
-                self.process_redeem_participation_bid_v3(
+                ),
+            "DecommissionAuctionManager" => self.process_decommission_auction_manager(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "RedeemPrintingV2Bid" => self.process_redeem_printing_v2_bid(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "WithdrawMasterEdition" => self.process_withdraw_master_edition(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "DeprecatedRedeemParticipationBidV2" => self
+                .process_deprecated_redeem_participation_bid_v2(
                     block,
                     transaction,
                     program_id,
                     accounts,
                     &mut input,
-                );
-            }
+                ),
+            "InitAuctionManagerV2" => self.process_init_auction_manager_v2(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "ValidateSafetyDepositBoxV2" => self.process_validate_safety_deposit_box_v2(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
+            "RedeemParticipationBidV3" => self.process_redeem_participation_bid_v3(
+                block,
+                transaction,
+                program_id,
+                accounts,
+                &mut input,
+            ),
             "EndAuction" => {
-                self.process_end_auction(block, transaction, program_id, accounts, &mut input);
+                self.process_end_auction(block, transaction, program_id, accounts, &mut input)
             }
             "SetStoreIndex" => {
-                self.process_set_store_index(block, transaction, program_id, accounts, &mut input);
+                self.process_set_store_index(block, transaction, program_id, accounts, &mut input)
             }
             "SetAuctionCache" => {
-                self.process_set_auction_cache(
-                    block,
-                    transaction,
-                    program_id,
-                    accounts,
-                    &mut input,
-                );
+                self.process_set_auction_cache(block, transaction, program_id, accounts, &mut input)
             }
             "SetStoreV2" => {
-                self.process_set_store_v2(block, transaction, program_id, accounts, &mut input);
+                self.process_set_store_v2(block, transaction, program_id, accounts, &mut input)
             }
-            _ => {}
+            _ => Ok(()),
+        }
+    }
+    /// Save each account under its role name in the instruction account layout
+    fn set_account_values(&self, accounts: &[Pubkey], input: &mut TransportValue) {
+        for (role, account) in account_layout(input.name.as_str())
+            .iter()
+            .zip(accounts.iter())
+        {
+            input.set_value(*role, TransValue::from(account.to_string()));
         }
     }
     fn process_deprecated_init_auction_manager_v1(
@@ -218,31 +196,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -251,31 +217,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -284,31 +238,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -317,31 +259,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -350,31 +280,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -383,31 +301,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -416,31 +322,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -449,31 +343,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -482,31 +364,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -515,31 +385,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -548,31 +406,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -581,31 +427,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -614,31 +448,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -647,31 +469,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -680,31 +490,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -713,31 +511,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -746,31 +532,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -779,31 +553,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -812,31 +574,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -845,31 +595,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -878,31 +616,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -911,31 +637,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -944,31 +658,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -977,31 +679,19 @@ impl Handler {
         block: &SolanaBlock,
         transaction: &TransactionWithStatusMeta,
         program_id: &Pubkey,
-        accounts: &Vec<Pubkey>,
+        accounts: &[Pubkey],
         input: &mut TransportValue,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), IndexerError> {
         println!(
             "call function process_initialize for handle incoming block {} with argument {:?}",
             block.block_number, &input.name
         );
         input.set_value("block_timestamp", TransValue::from(block.timestamp));
-        input.set_value(
-            "tx_hash",
-            TransValue::from(
-                transaction
-                    .transaction
-                    .signatures
-                    .iter()
-                    .map(|sig| sig.to_string())
-                    .collect::<Vec<String>>()
-                    .join(",'"),
-            ),
-        );
         input.set_value(
             "account_name",
             TransValue::from(accounts.get(0).map(|acc| acc.to_string())),
         );
-        input.save();
+        input.save(self.batch)?;
         println!("Write to db {:?}", input);
         Ok(())
     }
@@ -4,62 +4,129 @@ use massbit_solana_sdk::types::SolanaBlock;
 use crate::generated::handler::Handler;
 use crate::generated::instruction::*;
 //use crate::models::*;
-use crate::ADDRESS;
-use crate::SOLANA_CLIENT;
+use crate::config::{Program, INDEXER_CONFIG};
+use indexer_common::context::{Parsers, PluginContext};
+use indexer_common::decode::{self, DecodedBlock, InstructionHandler, TransactionContext};
+use indexer_common::enrichment::AccountStates;
+use indexer_common::error::IndexerError;
+use indexer_common::position::InstructionPosition;
+use indexer_common::token_metadata::handler::Handler as TokenMetadataHandler;
+use indexer_common::token_metadata::state::{decode_account, StateSource};
+use crate::{DECODE_POOL, ENRICHMENT, MINT_INDEX, NFT_ROWS, SOLANA_CLIENT};
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::rpc_config::RpcAccountInfoConfig;
 use solana_client::rpc_response::RpcResult;
 use solana_client::{client_error::Result as ClientResult, rpc_request::RpcRequest};
 use solana_program::account_info::AccountInfo;
-use solana_program::instruction::CompiledInstruction;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::account::Account;
 use solana_transaction_status::{parse_instruction, ConfirmedBlock, TransactionWithStatusMeta};
 use massbit_solana_sdk::transport::interface::InterfaceRegistrar;
+use massbit_solana_sdk::transport::TransportValue;
+use rayon::ThreadPool;
 use uuid::Uuid;
 
 
-pub fn handle_block(interface: &mut dyn InstructionParser, block: &SolanaBlock) -> Result<(), Box<dyn std::error::Error>> {
-    println!("Start handle_block, block.block_number: {}", block.block_number);
-    for (tx_ind, tran) in block.block.transactions.iter().enumerate() {
-        if tran
-            .transaction
-            .message
-            .account_keys
-            .iter()
-            .any(|key| key.to_string().as_str() == ADDRESS)
-        {
-            let entities = parse_instructions(interface, block, tran, tx_ind);
+/// Instructions are routed to the handler of their program by program id
+struct Indexer;
+
+impl InstructionHandler for Indexer {
+    /// Each program is indexed from its own start block
+    fn is_indexed(&self, program_id: &Pubkey, slot: u64) -> bool {
+        INDEXER_CONFIG
+            .program(program_id)
+            .map_or(false, |source| slot >= source.start_block)
+    }
+    fn process(&self, context: &TransactionContext, position: &InstructionPosition, program_id: &Pubkey, accounts: &[Pubkey], input: TransportValue) -> Result<(), IndexerError> {
+        let (block, tran) = (context.block, context.transaction);
+        match INDEXER_CONFIG.program(program_id).map(|source| source.program) {
+            Some(Program::Metaplex) => {
+                let handler = Handler { batch: context.batch, accounts: context.accounts };
+                handler.process(block, tran, position, program_id, accounts, input)
+            }
+            Some(Program::TokenMetadata) => {
+                let handler = TokenMetadataHandler { batch: context.batch, accounts: context.accounts };
+                handler.process(block, tran, position, program_id, accounts, input)
+            }
+            None => Ok(()),
         }
     }
-    Ok(())
 }
-fn parse_instructions(interface: &mut dyn InstructionParser, block: &SolanaBlock, tran: &TransactionWithStatusMeta, tx_ind: usize) {
-    for (ind, inst) in tran.transaction.message.instructions.iter().enumerate() {
-        let program_key = inst.program_id(tran.transaction.message.account_keys.as_slice());
-        if program_key.to_string().as_str() == ADDRESS {
-            let mut accounts = Vec::default();
-            let mut work = |unique_ind: usize, acc_ind: usize| {
-                if let Some(key) = tran.transaction.message.account_keys.get(acc_ind) {
-                    accounts.push(key.clone());
-                };
-                Ok(())
-            };
-            inst.visit_each_account(&mut work);
 
-            let handler = Handler {};
-            // Fixme: Get account_infos from chain take a lot of time. For now, use empty vector.
-            println!("Start unpack_instruction, inst {:?}", &inst);
-            match interface.unpack_instruction(inst.data.as_slice()) {
-                Ok(trans_value) => {
-                    println!("unpack_instruction Ok, trans_value: {:?}", &trans_value);
-                    handler.process(block, tran, program_key, &accounts, trans_value);
-                },
-                Err(e) => {
-                    println!("Error unpack_instruction: {:?}",e);
-                }
-            }
+/// Walk the block once, each instruction of an indexed program is decoded by the parser
+/// registered for its program id and saved by the handler of that program
+pub fn handle_block(context: &PluginContext, block: &SolanaBlock) -> Result<(), IndexerError> {
+    println!("Start handle_block, block.block_number: {}", block.block_number);
+    if block.block_number < INDEXER_CONFIG.start_block {
+        return Ok(());
+    }
+    // Accounts of the block are fetched in one batch before decoding
+    let accounts = match ENRICHMENT.as_ref() {
+        Some(enrichment) => enrichment.prefetch(&context.with_parsers(|parsers| {
+            decode::referenced_accounts(&Indexer, parsers, block)
+        }))?,
+        None => AccountStates::default(),
+    };
+    let decoded = context.with_parsers(|parsers| decode_block(parsers, &accounts, block, &DECODE_POOL));
+    let token_metadata = INDEXER_CONFIG.programs.iter().find(|source| source.program == Program::TokenMetadata);
+    if let Some(source) = token_metadata {
+        MINT_INDEX.index_accounts(&source.program_id, &accounts);
+    }
+    // Instructions are joined to their token and partial rows are merged in transaction order,
+    // after the parallel decoding
+    for values in decoded {
+        let values = match token_metadata {
+            Some(source) => NFT_ROWS.merge(MINT_INDEX.join(values?, block.block_number)),
+            None => values?,
+        };
+        context.write(values)?;
+    }
+    match token_metadata {
+        Some(source) => context.write(account_state_values(&source.program_id, &accounts)),
+        None => Ok(()),
+    }
+}
+/// Write the state of a Token Metadata account pushed by the host, accounts of other programs
+/// are ignored. The decoded state is written to the store with the next flush.
+pub fn handle_account_update(context: &PluginContext, slot: u64, key: &Pubkey, account: &Account) -> Result<(), IndexerError> {
+    match INDEXER_CONFIG.program(&account.owner).map(|source| source.program) {
+        Some(Program::TokenMetadata) => {}
+        _ => return Ok(()),
+    }
+    match decode_account(key, &account.data, slot, StateSource::AccountUpdate) {
+        Some(mut value) => {
+            MINT_INDEX.set_state_mint(key, &mut value);
+            context.write(vec![value])
+        }
+        None => Ok(()),
+    }
+}
+/// State entities of the Token Metadata accounts fetched by the enrichment stage, in address
+/// order, see the token-metadata plugin
+fn account_state_values(program_id: &Pubkey, accounts: &AccountStates) -> Vec<TransportValue> {
+    let mut keys = accounts
+        .iter()
+        .filter(|(_, state)| state.account.owner == *program_id)
+        .map(|(key, _)| key)
+        .collect::<Vec<&Pubkey>>();
+    keys.sort();
+    keys.into_iter()
+        .filter_map(|key| {
+            let state = &accounts[key];
+            let mut value = decode_account(key, &state.account.data, state.slot, StateSource::Enrichment)?;
+            MINT_INDEX.set_state_mint(key, &mut value);
+            Some(value)
+        })
+        .collect()
+}
+/// Decode the indexed transactions of a block into the entities of each transaction, see
+/// `indexer_common::decode::decode_block`
+pub fn decode_block(parsers: &Parsers, accounts: &AccountStates, block: &SolanaBlock, pool: &ThreadPool) -> DecodedBlock {
+    for source in INDEXER_CONFIG.programs.iter() {
+        if parsers.get(&source.program_id).is_none() {
+            println!("Warning: the parser of {} is not injected, its instructions are skipped", source.address);
         }
     }
+    decode::decode_block(&Indexer, parsers, accounts, block, pool)
 }
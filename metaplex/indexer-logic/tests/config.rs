@@ -0,0 +1,55 @@
+use block::config::{IndexerConfig, Program};
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+const MANIFEST: &str = r#"
+specVersion: 0.0.2
+dataSources:
+  - kind: solana
+    name: nft-indexer
+    network: devnet
+    source:
+      address: p1exdMJcjVao65QdewkaZRUnU6VPSXhus9n2GzWfh98
+      abi: nft
+      start_block: 120000
+  - kind: solana
+    name: token-metadata-indexer
+    network: devnet
+    source:
+      address: metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s
+      abi: token_metadata
+      start_block: 100000
+"#;
+
+mod config {
+    use super::*;
+
+    #[test]
+    fn read_programs_from_manifest() {
+        let config = IndexerConfig::from_manifest(MANIFEST);
+        assert_eq!(config.programs.len(), 2);
+        assert_eq!(config.network.as_str(), "devnet");
+        assert_eq!(config.start_block, 100000);
+        let metaplex = config
+            .program(&Pubkey::from_str("p1exdMJcjVao65QdewkaZRUnU6VPSXhus9n2GzWfh98").unwrap())
+            .unwrap();
+        assert_eq!(metaplex.program, Program::Metaplex);
+        assert_eq!(metaplex.start_block, 120000);
+        let token_metadata = config
+            .program(&Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s").unwrap())
+            .unwrap();
+        assert_eq!(token_metadata.program, Program::TokenMetadata);
+        assert!(config.program(&Pubkey::default()).is_none());
+    }
+
+    #[test]
+    fn skip_invalid_program_address() {
+        let manifest = MANIFEST.replace(
+            "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+            "not-a-pubkey",
+        );
+        let config = IndexerConfig::from_manifest(&manifest);
+        assert_eq!(config.programs.len(), 1);
+        assert_eq!(config.programs[0].program, Program::Metaplex);
+    }
+}
@@ -73,19 +73,103 @@ impl InstructionParser {
         input: mpl_metaplex::deprecated_state::AuctionManagerSettingsV1,
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("DeprecatedInitAuctionManagerV1");
+        // Winning config items are flattened, each item keeps the index of its winning config.
+        // The item count of each winning config keeps the configs without items. Indexes are u16,
+        // the instruction data cannot hold more winning configs.
         transport_value.set_value(
-            "winning_configs",
-            Value::from(
+            "winning_config_item_counts",
+            Value::List(
                 input
                     .winning_configs
                     .iter()
-                    .map(|item| format!("{:?}", item))
-                    .collect::<Vec<String>>(),
+                    .map(|config| Value::from(config.items.len() as u16))
+                    .collect(),
+            ),
+        );
+        let items = input
+            .winning_configs
+            .iter()
+            .enumerate()
+            .flat_map(|(config_index, config)| {
+                config.items.iter().map(move |item| (config_index, item))
+            })
+            .collect::<Vec<_>>();
+        transport_value.set_value(
+            "winning_config_item_config_indexes",
+            Value::List(
+                items
+                    .iter()
+                    .map(|(config_index, _)| Value::from(*config_index as u16))
+                    .collect(),
+            ),
+        );
+        transport_value.set_value(
+            "winning_config_item_safety_deposit_box_indexes",
+            Value::List(
+                items
+                    .iter()
+                    .map(|(_, item)| Value::from(item.safety_deposit_box_index))
+                    .collect(),
+            ),
+        );
+        transport_value.set_value(
+            "winning_config_item_amounts",
+            Value::List(
+                items
+                    .iter()
+                    .map(|(_, item)| Value::from(item.amount))
+                    .collect(),
             ),
         );
         transport_value.set_value(
-            "participation_config",
-            Value::from(input.participation_config.map(|item| format!("{:?}", item))),
+            "winning_config_item_types",
+            Value::List(
+                items
+                    .iter()
+                    .map(|(_, item)| match item.winning_config_type {
+                        mpl_metaplex::state::WinningConfigType::TokenOnlyTransfer => {
+                            Value::from("TokenOnlyTransfer")
+                        }
+                        mpl_metaplex::state::WinningConfigType::FullRightsTransfer => {
+                            Value::from("FullRightsTransfer")
+                        }
+                        mpl_metaplex::state::WinningConfigType::PrintingV1 => {
+                            Value::from("PrintingV1")
+                        }
+                        mpl_metaplex::state::WinningConfigType::PrintingV2 => {
+                            Value::from("PrintingV2")
+                        }
+                        mpl_metaplex::state::WinningConfigType::Participation => {
+                            Value::from("Participation")
+                        }
+                    })
+                    .collect(),
+            ),
+        );
+        set_participation_constraint_values(
+            &mut transport_value,
+            input
+                .participation_config
+                .as_ref()
+                .map(|config| (&config.winner_constraint, &config.non_winning_constraint)),
+        );
+        transport_value.set_value(
+            "participation_safety_deposit_box_index",
+            Value::from(
+                input
+                    .participation_config
+                    .as_ref()
+                    .map(|config| config.safety_deposit_box_index),
+            ),
+        );
+        transport_value.set_value(
+            "participation_fixed_price",
+            Value::from(
+                input
+                    .participation_config
+                    .as_ref()
+                    .and_then(|config| config.fixed_price),
+            ),
         );
         Ok(transport_value)
     }
@@ -329,22 +413,49 @@ impl InstructionParser {
             },
         );
         transport_value.set_value(
-            "amount_ranges",
-            Value::from(
+            "amount_range_amounts",
+            Value::List(
                 input
                     .amount_ranges
                     .iter()
-                    .map(|item| format!("{:?}", item))
-                    .collect::<Vec<String>>(),
+                    .map(|item| Value::from(item.0))
+                    .collect(),
             ),
         );
         transport_value.set_value(
-            "participation_config",
-            Value::from(input.participation_config.map(|item| format!("{:?}", item))),
+            "amount_range_lengths",
+            Value::List(
+                input
+                    .amount_ranges
+                    .iter()
+                    .map(|item| Value::from(item.1))
+                    .collect(),
+            ),
+        );
+        set_participation_constraint_values(
+            &mut transport_value,
+            input
+                .participation_config
+                .as_ref()
+                .map(|config| (&config.winner_constraint, &config.non_winning_constraint)),
         );
         transport_value.set_value(
-            "participation_state",
-            Value::from(input.participation_state.map(|item| format!("{:?}", item))),
+            "participation_fixed_price",
+            Value::from(
+                input
+                    .participation_config
+                    .as_ref()
+                    .and_then(|config| config.fixed_price),
+            ),
+        );
+        transport_value.set_value(
+            "participation_collected_to_accept_payment",
+            Value::from(
+                input
+                    .participation_state
+                    .as_ref()
+                    .map(|state| state.collected_to_accept_payment),
+            ),
         );
         Ok(transport_value)
     }
@@ -361,7 +472,15 @@ impl InstructionParser {
         input: mpl_metaplex::instruction::EndAuctionArgs,
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("EndAuction");
-        transport_value.set_value("reveal", Value::Null);
+        // Reveal of a blinded auction is the pair (price, salt)
+        transport_value.set_value(
+            "reveal_price",
+            Value::from(input.reveal.map(|(price, _)| price)),
+        );
+        transport_value.set_value(
+            "reveal_salt",
+            Value::from(input.reveal.map(|(_, salt)| salt)),
+        );
         Ok(transport_value)
     }
     fn unpack_set_store_index(
@@ -383,7 +502,43 @@ impl InstructionParser {
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("SetStoreV2");
         transport_value.set_value("public", Value::from(input.public));
-        transport_value.set_value("settings_uri", Value::Null);
+        transport_value.set_value("settings_uri", Value::from(input.settings_uri));
         Ok(transport_value)
     }
 }
+/// Set the constraints shared by `ParticipationConfigV1` and `ParticipationConfigV2`
+fn set_participation_constraint_values(
+    transport_value: &mut TransportValue,
+    constraints: Option<(
+        &mpl_metaplex::state::WinningConstraint,
+        &mpl_metaplex::state::NonWinningConstraint,
+    )>,
+) {
+    transport_value.set_value(
+        "participation_winner_constraint",
+        match constraints.map(|(winner_constraint, _)| winner_constraint) {
+            Some(mpl_metaplex::state::WinningConstraint::NoParticipationPrize) => {
+                Value::from("NoParticipationPrize")
+            }
+            Some(mpl_metaplex::state::WinningConstraint::ParticipationPrizeGiven) => {
+                Value::from("ParticipationPrizeGiven")
+            }
+            None => Value::Null,
+        },
+    );
+    transport_value.set_value(
+        "participation_non_winning_constraint",
+        match constraints.map(|(_, non_winning_constraint)| non_winning_constraint) {
+            Some(mpl_metaplex::state::NonWinningConstraint::NoParticipationPrize) => {
+                Value::from("NoParticipationPrize")
+            }
+            Some(mpl_metaplex::state::NonWinningConstraint::GivenForFixedPrice) => {
+                Value::from("GivenForFixedPrice")
+            }
+            Some(mpl_metaplex::state::NonWinningConstraint::GivenForBidPrice) => {
+                Value::from("GivenForBidPrice")
+            }
+            None => Value::Null,
+        },
+    );
+}
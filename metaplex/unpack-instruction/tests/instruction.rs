@@ -0,0 +1,189 @@
+use borsh::BorshSerialize;
+use mpl_metaplex::deprecated_state::{
+    AuctionManagerSettingsV1, ParticipationConfigV1, WinningConfig, WinningConfigItem,
+};
+use mpl_metaplex::instruction::{EndAuctionArgs, MetaplexInstruction};
+use mpl_metaplex::state::{NonWinningConstraint, WinningConfigType, WinningConstraint};
+use transport::interface::InstructionParser as InstructionParserTrait;
+use transport::{TransportValue, Value};
+use unpack_instruction::instruction::InstructionParser;
+
+/// Serialize an instruction like a client and unpack it with the parser
+fn unpack(instruction: MetaplexInstruction) -> TransportValue {
+    let data = instruction.try_to_vec().unwrap();
+    InstructionParser.unpack_instruction(&data).unwrap()
+}
+
+fn item(
+    safety_deposit_box_index: u8,
+    amount: u8,
+    winning_config_type: WinningConfigType,
+) -> WinningConfigItem {
+    WinningConfigItem {
+        safety_deposit_box_index,
+        amount,
+        winning_config_type,
+    }
+}
+
+fn list(values: Vec<Value>) -> Option<Value> {
+    Some(Value::List(values))
+}
+
+mod instruction {
+    use super::*;
+
+    #[test]
+    fn flatten_winning_config_items() {
+        let settings = AuctionManagerSettingsV1 {
+            winning_configs: vec![
+                WinningConfig {
+                    items: vec![
+                        item(0, 1, WinningConfigType::TokenOnlyTransfer),
+                        item(2, 3, WinningConfigType::PrintingV2),
+                    ],
+                },
+                // A winning config without items is kept in the item counts
+                WinningConfig { items: vec![] },
+                WinningConfig {
+                    items: vec![item(1, 1, WinningConfigType::FullRightsTransfer)],
+                },
+            ],
+            participation_config: Some(ParticipationConfigV1 {
+                winner_constraint: WinningConstraint::ParticipationPrizeGiven,
+                non_winning_constraint: NonWinningConstraint::GivenForFixedPrice,
+                safety_deposit_box_index: 4,
+                fixed_price: Some(1_000_000),
+            }),
+        };
+        let value = unpack(MetaplexInstruction::DeprecatedInitAuctionManagerV1(
+            settings,
+        ));
+        assert_eq!(value.name, "DeprecatedInitAuctionManagerV1");
+        assert_eq!(
+            value.values.get("winning_config_item_counts").cloned(),
+            list(vec![
+                Value::from(2_u16),
+                Value::from(0_u16),
+                Value::from(1_u16)
+            ])
+        );
+        assert_eq!(
+            value
+                .values
+                .get("winning_config_item_config_indexes")
+                .cloned(),
+            list(vec![
+                Value::from(0_u16),
+                Value::from(0_u16),
+                Value::from(2_u16)
+            ])
+        );
+        assert_eq!(
+            value
+                .values
+                .get("winning_config_item_safety_deposit_box_indexes")
+                .cloned(),
+            list(vec![
+                Value::from(0_u8),
+                Value::from(2_u8),
+                Value::from(1_u8)
+            ])
+        );
+        assert_eq!(
+            value.values.get("winning_config_item_amounts").cloned(),
+            list(vec![
+                Value::from(1_u8),
+                Value::from(3_u8),
+                Value::from(1_u8)
+            ])
+        );
+        assert_eq!(
+            value.values.get("winning_config_item_types").cloned(),
+            list(vec![
+                Value::from("TokenOnlyTransfer"),
+                Value::from("PrintingV2"),
+                Value::from("FullRightsTransfer")
+            ])
+        );
+        assert_eq!(
+            value.values.get("participation_winner_constraint").cloned(),
+            Some(Value::from("ParticipationPrizeGiven"))
+        );
+        assert_eq!(
+            value
+                .values
+                .get("participation_non_winning_constraint")
+                .cloned(),
+            Some(Value::from("GivenForFixedPrice"))
+        );
+        assert_eq!(
+            value
+                .values
+                .get("participation_safety_deposit_box_index")
+                .cloned(),
+            Some(Value::from(Some(4_u8)))
+        );
+        assert_eq!(
+            value.values.get("participation_fixed_price").cloned(),
+            Some(Value::from(Some(1_000_000_u64)))
+        );
+    }
+
+    #[test]
+    fn unpack_auction_without_winning_items() {
+        let settings = AuctionManagerSettingsV1 {
+            winning_configs: vec![WinningConfig { items: vec![] }],
+            participation_config: None,
+        };
+        let value = unpack(MetaplexInstruction::DeprecatedInitAuctionManagerV1(
+            settings,
+        ));
+        assert_eq!(
+            value.values.get("winning_config_item_counts").cloned(),
+            list(vec![Value::from(0_u16)])
+        );
+        assert_eq!(
+            value
+                .values
+                .get("winning_config_item_config_indexes")
+                .cloned(),
+            list(vec![])
+        );
+        assert_eq!(
+            value.values.get("participation_winner_constraint").cloned(),
+            Some(Value::Null)
+        );
+        assert_eq!(
+            value.values.get("participation_fixed_price").cloned(),
+            Some(Value::Null)
+        );
+    }
+
+    #[test]
+    fn split_reveal_of_end_auction() {
+        let value = unpack(MetaplexInstruction::EndAuction(EndAuctionArgs {
+            reveal: Some((5_000, 42)),
+        }));
+        assert_eq!(value.name, "EndAuction");
+        assert_eq!(
+            value.values.get("reveal_price").cloned(),
+            Some(Value::from(Some(5_000_u64)))
+        );
+        assert_eq!(
+            value.values.get("reveal_salt").cloned(),
+            Some(Value::from(Some(42_u64)))
+        );
+
+        let value = unpack(MetaplexInstruction::EndAuction(EndAuctionArgs {
+            reveal: None,
+        }));
+        assert_eq!(value.values.get("reveal_price").cloned(), Some(Value::Null));
+        assert_eq!(value.values.get("reveal_salt").cloned(), Some(Value::Null));
+    }
+
+    #[test]
+    fn reject_unknown_instruction() {
+        assert!(InstructionParser.unpack_instruction(&[255]).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use indexer_logic::config::INDEXER_CONFIG;
+use indexer_logic::mapping::involves_program;
+use solana_program::hash::Hash;
+use solana_program::instruction::CompiledInstruction;
+use solana_program::message::Message;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction;
+use solana_transaction_status::TransactionWithStatusMeta;
+
+const TRANSACTION_COUNT: usize = 5000;
+const ACCOUNT_COUNT: usize = 12;
+
+/// Transactions with random accounts, one in ten calls the indexed program
+fn synthetic_block(program_id: Pubkey) -> Vec<TransactionWithStatusMeta> {
+    (0..TRANSACTION_COUNT)
+        .map(|ind| {
+            let mut account_keys = (0..ACCOUNT_COUNT)
+                .map(|_| Pubkey::new_unique())
+                .collect::<Vec<Pubkey>>();
+            if ind % 10 == 0 {
+                account_keys[ACCOUNT_COUNT - 1] = program_id;
+            }
+            let instructions = (0..3)
+                .map(|_| {
+                    CompiledInstruction::new_from_raw_parts(
+                        (ACCOUNT_COUNT - 1) as u8,
+                        vec![0; 32],
+                        vec![0, 1, 2],
+                    )
+                })
+                .collect();
+            let message = Message::new_with_compiled_instructions(
+                1,
+                0,
+                1,
+                account_keys,
+                Hash::default(),
+                instructions,
+            );
+            TransactionWithStatusMeta {
+                transaction: Transaction {
+                    signatures: vec![Signature::default()],
+                    message,
+                },
+                meta: None,
+            }
+        })
+        .collect()
+}
+
+/// Matching as done before the address was parsed once: base58 encode every key
+fn count_by_string(transactions: &[TransactionWithStatusMeta]) -> usize {
+    transactions
+        .iter()
+        .filter(|tran| {
+            tran.transaction
+                .message
+                .account_keys
+                .iter()
+                .any(|key| key.to_string().as_str() == INDEXER_CONFIG.address)
+        })
+        .map(|tran| {
+            let keys = tran.transaction.message.account_keys.as_slice();
+            tran.transaction
+                .message
+                .instructions
+                .iter()
+                .filter(|inst| inst.program_id(keys).to_string().as_str() == INDEXER_CONFIG.address)
+                .count()
+        })
+        .sum()
+}
+
+fn count_by_pubkey(transactions: &[TransactionWithStatusMeta]) -> usize {
+    transactions
+        .iter()
+        .filter(|tran| involves_program(tran))
+        .map(|tran| {
+            let keys = tran.transaction.message.account_keys.as_slice();
+            tran.transaction
+                .message
+                .instructions
+                .iter()
+                .filter(|inst| INDEXER_CONFIG.is_program(inst.program_id(keys)))
+                .count()
+        })
+        .sum()
+}
+
+fn block_scan(c: &mut Criterion) {
+    let program_id = INDEXER_CONFIG
+        .program_id
+        .expect("Invalid program address in the manifest");
+    let transactions = synthetic_block(program_id);
+    assert_eq!(
+        count_by_string(&transactions),
+        count_by_pubkey(&transactions)
+    );
+
+    let mut group = c.benchmark_group("block_scan");
+    group.throughput(Throughput::Elements(TRANSACTION_COUNT as u64));
+    group.bench_function("to_string", |b| {
+        b.iter(|| count_by_string(black_box(&transactions)))
+    });
+    group.bench_function("pubkey", |b| {
+        b.iter(|| count_by_pubkey(black_box(&transactions)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, block_scan);
+criterion_main!(benches);
@@ -0,0 +1,105 @@
+use indexer_common::config::default_rpc_url;
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use solana_program::pubkey::Pubkey;
+use std::env;
+use std::fs;
+use std::str::FromStr;
+
+/// Manifest built into the plugin, used when `SUBGRAPH_MANIFEST` is not set
+const DEFAULT_MANIFEST: &str = include_str!("subgraph.yaml");
+
+lazy_static! {
+    pub static ref INDEXER_CONFIG: IndexerConfig = IndexerConfig::load();
+}
+
+/// Program and network settings read from the `subgraph.yaml` manifest.
+/// Each value can be overridden by an env:
+/// `PROGRAM_ADDRESS`, `SOLANA_NETWORK`, `START_BLOCK` and `SOLANA_RPC_URL`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexerConfig {
+    pub address: String,
+    /// `address` parsed once, so the block scan compares bytes instead of base58 strings
+    pub program_id: Option<Pubkey>,
+    pub network: String,
+    pub start_block: u64,
+    pub rpc_url: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Manifest {
+    #[serde(rename = "dataSources", default)]
+    data_sources: Vec<DataSource>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DataSource {
+    network: Option<String>,
+    #[serde(default)]
+    source: Source,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Source {
+    address: Option<String>,
+    start_block: Option<u64>,
+    rpc_url: Option<String>,
+}
+
+impl IndexerConfig {
+    /// Read the manifest from the `SUBGRAPH_MANIFEST` path, or the one built into the plugin
+    pub fn load() -> Self {
+        let content = match env::var("SUBGRAPH_MANIFEST") {
+            Ok(path) => fs::read_to_string(&path).unwrap_or_else(|err| {
+                println!(
+                    "Cannot read manifest {}: {}, use the default one",
+                    path, err
+                );
+                DEFAULT_MANIFEST.to_string()
+            }),
+            Err(_) => DEFAULT_MANIFEST.to_string(),
+        };
+        let config = IndexerConfig::from_manifest(&content);
+        println!("Indexer config {:?}", &config);
+        config
+    }
+    pub fn from_manifest(content: &str) -> Self {
+        let manifest = serde_yaml::from_str::<Manifest>(content).unwrap_or_else(|err| {
+            println!("Cannot parse manifest: {}", err);
+            Manifest::default()
+        });
+        let data_source = manifest.data_sources.into_iter().next().unwrap_or_default();
+        let network = env::var("SOLANA_NETWORK")
+            .ok()
+            .or(data_source.network)
+            .unwrap_or_else(|| String::from("mainnet"));
+        let address = env::var("PROGRAM_ADDRESS")
+            .ok()
+            .or(data_source.source.address)
+            .unwrap_or_default();
+        IndexerConfig {
+            program_id: parse_program_id(&address),
+            address,
+            start_block: env::var("START_BLOCK")
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .or(data_source.source.start_block)
+                .unwrap_or_default(),
+            rpc_url: env::var("SOLANA_RPC_URL")
+                .ok()
+                .or(data_source.source.rpc_url)
+                .unwrap_or_else(|| default_rpc_url(&network).to_string()),
+            network,
+        }
+    }
+    pub fn is_program(&self, key: &Pubkey) -> bool {
+        self.program_id.as_ref() == Some(key)
+    }
+}
+
+/// An invalid address matches no program, so nothing is indexed
+fn parse_program_id(address: &str) -> Option<Pubkey> {
+    Pubkey::from_str(address)
+        .map_err(|err| println!("Invalid program address {}: {}", address, err))
+        .ok()
+}
@@ -1 +0,0 @@
-pub mod handler;
\ No newline at end of file
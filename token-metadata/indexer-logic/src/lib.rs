@@ -1,4 +1,4 @@
-pub mod generated;
+pub mod config;
 pub mod mapping;
 
 use lazy_static::lazy_static;
@@ -13,26 +13,131 @@ use massbit_solana_sdk::{
     store::IndexStore,
     types::SolanaBlock,
 };
+use rayon::{ThreadPool, ThreadPoolBuilder};
 use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use std::env;
+use crate::config::INDEXER_CONFIG;
+use indexer_common::config::{DECODE_CONFIG, ENRICHMENT_CONFIG, FLUSH_CONFIG, LOCAL_STATE_CONFIG};
+use indexer_common::context::PluginContext;
+use indexer_common::enrichment::Enrichment;
+use indexer_common::error::IndexerError;
+use indexer_common::local_state::LocalState;
+use indexer_common::token_metadata::mints::MintIndex;
+use indexer_common::token_metadata::rows::NftRows;
 use std::error::Error;
 use std::sync::Arc;
+use std::time::Instant;
 lazy_static! {
-    pub static ref SOLANA_CLIENT: Arc<RpcClient> = Arc::new(RpcClient::new(
-        env::var("SOLANA_RPC_URL").unwrap_or(String::from("http://194.163.156.242:8899"))
-    ));
+    pub static ref SOLANA_CLIENT: Arc<RpcClient> =
+        Arc::new(RpcClient::new(INDEXER_CONFIG.rpc_url.clone()));
+    /// Account fetcher, `None` unless `ENRICH_ACCOUNTS` is set
+    pub static ref ENRICHMENT: Option<Enrichment> = if ENRICHMENT_CONFIG.enabled {
+        Some(Enrichment::new(
+            SOLANA_CLIENT.clone(),
+            ENRICHMENT_CONFIG.cache_size,
+        ))
+    } else {
+        None
+    };
 }
-pub const ADDRESS: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
 
+lazy_static! {
+    pub static ref CONTEXT: PluginContext = PluginContext::default();
+    /// Worker pool decoding the transactions of a block
+    pub static ref DECODE_POOL: ThreadPool = ThreadPoolBuilder::new()
+        .num_threads(DECODE_CONFIG.workers)
+        .thread_name(|ind| format!("decode-worker-{}", ind))
+        .build()
+        .expect("Cannot build the decode worker pool");
+    /// Facts learnt from the indexed blocks, kept across restarts
+    pub static ref LOCAL_STATE: LocalState = LocalState::open(
+        &LOCAL_STATE_CONFIG.path.join("token-metadata"),
+        LOCAL_STATE_CONFIG.cache_size,
+    )
+    .expect("Cannot open the local state");
+    /// Mint of the metadata and edition accounts
+    pub static ref MINT_INDEX: MintIndex =
+        MintIndex::new(&LOCAL_STATE).expect("Cannot open the mint index");
+    /// Creators of the tokens, pending until the store is flushed
+    pub static ref NFT_ROWS: NftRows =
+        NftRows::new(&LOCAL_STATE).expect("Cannot open the creators of the tokens");
+}
+export_plugin!(register);
+
+/// Symbols written by hosts older than `inject_store` and `inject_interface`, they are moved
+/// into the context when the plugin is first called
 #[doc(hidden)]
 #[no_mangle]
 pub static mut STORE: Option<&mut dyn IndexStore> = None;
+#[doc(hidden)]
 #[no_mangle]
 pub static mut INTERFACE: Option<&mut dyn InstructionParser> = None;
-export_plugin!(register);
+
+/// Move the store and the parser set through the legacy symbols into the context
+fn adopt_legacy_symbols() {
+    // The host writes the symbols before calling the plugin, from the thread calling it
+    unsafe {
+        if let Some(store) = STORE.take() {
+            inject_store(store);
+        }
+        if let Some(interface) = INTERFACE.take() {
+            inject_interface(interface);
+        }
+    }
+}
+
+/// Called by the host to inject the store before the first `handle_blocks`
+#[no_mangle]
+#[allow(improper_ctypes_definitions)]
+pub extern "C" fn inject_store(store: &'static mut dyn IndexStore) {
+    CONTEXT.set_store(store);
+}
+/// Called by the host to inject the instruction parser before the first `handle_blocks`.
+/// The parser is not known to be thread safe, so transactions are decoded serially.
+#[no_mangle]
+#[allow(improper_ctypes_definitions)]
+pub extern "C" fn inject_interface(interface: &'static mut dyn InstructionParser) {
+    match INDEXER_CONFIG.program_id {
+        Some(program_id) => CONTEXT.set_interface(program_id, interface),
+        None => println!(
+            "Invalid program address {}, the parser is not registered",
+            INDEXER_CONFIG.address
+        ),
+    }
+}
+/// Same as `inject_interface` for a parser the host knows to be `Sync`, transactions are then
+/// decoded by `DECODE_WORKERS` threads
+#[no_mangle]
+#[allow(improper_ctypes_definitions)]
+pub extern "C" fn inject_sync_interface(interface: &'static (dyn InstructionParser + Sync)) {
+    match INDEXER_CONFIG.program_id {
+        Some(program_id) => CONTEXT.set_sync_interface(program_id, interface),
+        None => println!(
+            "Invalid program address {}, the parser is not registered",
+            INDEXER_CONFIG.address
+        ),
+    }
+}
+
+/// Called by the host with an account owned by the program, for example from an account
+/// subscription. The decoded state is written to the store with the next flush.
+#[no_mangle]
+#[allow(improper_ctypes_definitions)]
+pub extern "C" fn handle_account_update(
+    slot: u64,
+    key: &Pubkey,
+    account: &Account,
+) -> Result<(), Box<dyn Error>> {
+    adopt_legacy_symbols();
+    mapping::handle_account_update(&CONTEXT, slot, key, account)?;
+    Ok(())
+}
 
 #[allow(dead_code, improper_ctypes_definitions)]
 extern "C" fn register(registrar: &mut dyn PluginRegistrar) {
+    lazy_static::initialize(&INDEXER_CONFIG);
     registrar.register_solana_handler(Box::new(SolanaHandlerAdapter));
 }
 
@@ -40,19 +145,45 @@ extern "C" fn register(registrar: &mut dyn PluginRegistrar) {
 pub struct SolanaHandlerAdapter;
 
 impl SolanaHandler for SolanaHandlerAdapter {
+    /// Returns the highest slot flushed to the store.
+    /// Blocks are flushed in batches with the hash of the last block, each entity keeps
+    /// the hash of its own block in `block_hash`.
     fn handle_blocks(&self, blocks: &Vec<SolanaBlock>) -> Result<i64, Box<dyn Error>> {
         println!("Start handle_blocks, block len: {}", blocks.len());
+        adopt_legacy_symbols();
         let mut block_slot = -1_i64;
-        // Todo: Rewrite the flush so it will flush after finish the array of blocks for better performance. For now, we flush after each block.
-        unsafe {
-            if let Some(interface) = INTERFACE.as_mut() {
-                for block in blocks {
-                    mapping::handle_block(*interface, block);
-                    block_slot = block_slot.max(block.block_number as i64);
-                    if let Some(store) = &mut STORE {
-                        store.flush(&block.block.blockhash, block.block_number);
-                    }
-                }
+        let mut pending_blocks = 0_usize;
+        let mut pending_slot = -1_i64;
+        let mut last_flush = Instant::now();
+        // Errors are returned before the failing block is flushed, so the host can retry it instead of moving the cursor past it.
+        for (ind, block) in blocks.iter().enumerate() {
+            if let Err(err) = mapping::handle_block(&CONTEXT, block) {
+                // The blocks since the last flush are handled again by the host
+                NFT_ROWS.discard();
+                return Err(err.into());
+            }
+            pending_blocks += 1;
+            pending_slot = pending_slot.max(block.block_number as i64);
+            if pending_blocks >= FLUSH_CONFIG.max_batch_size
+                || last_flush.elapsed() >= FLUSH_CONFIG.max_delay
+                || ind == blocks.len() - 1
+            {
+                CONTEXT
+                    .with_store(|store| store.flush(&block.block.blockhash, block.block_number))?
+                    .map_err(|err| IndexerError::Store {
+                        entity: format!("block {}", block.block_number),
+                        message: err.to_string(),
+                    })?;
+                // Written after the store, a restart replays the blocks that were not flushed
+                NFT_ROWS.commit()?;
+                LOCAL_STATE.flush()?;
+                println!(
+                    "Flushed {} blocks, last block {}",
+                    pending_blocks, block.block_number
+                );
+                pending_blocks = 0;
+                last_flush = Instant::now();
+                block_slot = block_slot.max(pending_slot);
             }
         }
         Ok(block_slot)
@@ -1,89 +1,134 @@
-use std::collections::HashMap;
-use crate::generated::handler::Handler;
-use crate::ADDRESS;
-use crate::SOLANA_CLIENT;
+use crate::config::INDEXER_CONFIG;
+use indexer_common::context::{Parsers, PluginContext};
+use indexer_common::decode::{self, DecodedBlock, InstructionHandler, TransactionContext};
+use indexer_common::enrichment::AccountStates;
+use indexer_common::error::IndexerError;
+use indexer_common::position::InstructionPosition;
+use indexer_common::token_metadata::handler::Handler;
+use indexer_common::token_metadata::state::{decode_account, StateSource};
+use crate::{DECODE_POOL, ENRICHMENT, MINT_INDEX, NFT_ROWS, SOLANA_CLIENT};
 use massbit_solana_sdk::smart_contract::{InstructionParser, SmartContractProxy};
 use massbit_solana_sdk::transport::interface::InterfaceRegistrar;
+use massbit_solana_sdk::transport::TransportValue;
 use massbit_solana_sdk::types::SolanaBlock;
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::rpc_config::RpcAccountInfoConfig;
 use solana_client::rpc_response::RpcResult;
 use solana_client::{client_error::Result as ClientResult, rpc_request::RpcRequest};
 use solana_program::account_info::AccountInfo;
-use solana_program::instruction::CompiledInstruction;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::account::Account;
 use solana_transaction_status::{parse_instruction, ConfirmedBlock, TransactionWithStatusMeta};
+use rayon::ThreadPool;
 use std::sync::Arc;
 use uuid::Uuid;
 
-pub fn handle_block(
-    interface: &mut dyn InstructionParser,
-    block: &SolanaBlock,
-) -> Result<(), Box<dyn std::error::Error>> {
+/// Instructions of the token metadata program are saved by the shared handler
+struct Indexer;
+
+impl InstructionHandler for Indexer {
+    fn is_indexed(&self, program_id: &Pubkey, _slot: u64) -> bool {
+        INDEXER_CONFIG.is_program(program_id)
+    }
+    fn process(
+        &self,
+        context: &TransactionContext,
+        position: &InstructionPosition,
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+        input: TransportValue,
+    ) -> Result<(), IndexerError> {
+        let handler = Handler {
+            batch: context.batch,
+            accounts: context.accounts,
+        };
+        handler.process(context.block, context.transaction, position, program_id, accounts, input)
+    }
+}
+
+pub fn handle_block(context: &PluginContext, block: &SolanaBlock) -> Result<(), IndexerError> {
     println!(
         "Start handle_block, block.block_number: {}",
         block.block_number
     );
-    for (tx_ind, tran) in block.block.transactions.iter().enumerate() {
-        if tran
-            .transaction
-            .message
-            .account_keys
-            .iter()
-            .any(|key| key.to_string().as_str() == ADDRESS)
-        {
-            let entities = parse_instructions(interface, block, tran, tx_ind);
-        }
+    if block.block_number < INDEXER_CONFIG.start_block {
+        return Ok(());
+    }
+    let program_id = INDEXER_CONFIG
+        .program_id
+        .ok_or_else(|| IndexerError::MissingInterface(INDEXER_CONFIG.address.clone()))?;
+    // Accounts of the block are fetched in one batch before decoding
+    let accounts = match ENRICHMENT.as_ref() {
+        Some(enrichment) => enrichment.prefetch(&context.with_parsers(|parsers| {
+            decode::referenced_accounts(&Indexer, parsers, block)
+        }))?,
+        None => AccountStates::default(),
+    };
+    let decoded = context.with_parsers(|parsers| decode_block(parsers, &accounts, block, &DECODE_POOL));
+    MINT_INDEX.index_accounts(&program_id, &accounts);
+    // Instructions are joined to their token and partial rows are merged in transaction order,
+    // after the parallel decoding
+    for values in decoded {
+        let values = MINT_INDEX.join(values?, block.block_number);
+        context.write(NFT_ROWS.merge(values))?;
     }
-    Ok(())
+    context.write(account_state_values(&accounts))
 }
-fn parse_instructions(
-    interface: &mut dyn InstructionParser,
+/// Decode the indexed transactions of a block into the entities of each transaction, see
+/// `indexer_common::decode::decode_block`
+pub fn decode_block(
+    parsers: &Parsers,
+    accounts: &AccountStates,
     block: &SolanaBlock,
-    tran: &TransactionWithStatusMeta,
-    tx_ind: usize,
-) {
-    let map_inner_instructions  = tran.meta.as_ref().and_then(|trans_meta|
-        trans_meta.inner_instructions.as_ref().map(|insts| insts.iter().map(|inner_inst|{
-            (inner_inst.index, &inner_inst.instructions)
-        }).collect::<HashMap<u8, &Vec<CompiledInstruction>>>())).unwrap_or_default();
-    let handler = Handler {};
-    for (ind, inst) in tran.transaction.message.instructions.iter().enumerate() {
-        process_instruction(interface, &handler, block, tran, inst);
-        let inner_key = ind as u8;
-        if let Some(inner_instructions) = map_inner_instructions.get(&inner_key) {
-            inner_instructions.iter().for_each(|inner_instruction| {
-                process_instruction(interface, &handler, block, tran, inner_instruction);
-            })
+    pool: &ThreadPool,
+) -> DecodedBlock {
+    if let Some(program_id) = INDEXER_CONFIG.program_id {
+        if parsers.get(&program_id).is_none() {
+            println!("Warning: the parser of {} is not injected, its instructions are skipped", program_id);
         }
     }
+    decode::decode_block(&Indexer, parsers, accounts, block, pool)
 }
-
-fn process_instruction(interface: &mut dyn InstructionParser, handler: &Handler, block: &SolanaBlock, tran: &TransactionWithStatusMeta, instruction: &CompiledInstruction) {
-    let program_key = instruction.program_id(tran.transaction.message.account_keys.as_slice());
-    if program_key.to_string().as_str() == ADDRESS {
-        let mut accounts = Vec::default();
-        let mut work = |unique_ind: usize, acc_ind: usize| {
-            if let Some(key) = tran.transaction.message.account_keys.get(acc_ind) {
-                accounts.push(key.clone());
-            };
-            Ok(())
-        };
-        instruction.visit_each_account(&mut work);
-
-        // Fixme: Get account_infos from chain take a lot of time. For now, use empty vector.
-
-        println!("Start unpack_instruction, inst {:?}", &instruction);
-        match interface.unpack_instruction(instruction.data.as_slice()) {
-            Ok(trans_value) => {
-                println!("unpack_instruction Ok, trans_value: {:?}", &trans_value);
-                &handler.process(block, tran, program_key, &accounts, trans_value);
-            }
-            Err(e) => {
-                println!("Error unpack_instruction: {:?}", e);
-            }
+/// Save the state of a program account pushed by the host
+pub fn handle_account_update(
+    context: &PluginContext,
+    slot: u64,
+    key: &Pubkey,
+    account: &Account,
+) -> Result<(), IndexerError> {
+    if !INDEXER_CONFIG.is_program(&account.owner) {
+        return Ok(());
+    }
+    match decode_account(key, &account.data, slot, StateSource::AccountUpdate) {
+        Some(mut value) => {
+            MINT_INDEX.set_state_mint(key, &mut value);
+            context.write(vec![value])
         }
+        None => Ok(()),
     }
-}
\ No newline at end of file
+}
+/// State entities of the program accounts fetched by the enrichment stage, in address order.
+/// Each entity is stamped with the slot the RPC node returned the account at, edition states
+/// take their mint from the mint index.
+fn account_state_values(accounts: &AccountStates) -> Vec<TransportValue> {
+    let mut keys = accounts
+        .iter()
+        .filter(|(_, state)| INDEXER_CONFIG.is_program(&state.account.owner))
+        .map(|(key, _)| key)
+        .collect::<Vec<&Pubkey>>();
+    keys.sort();
+    keys.into_iter()
+        .filter_map(|key| {
+            let state = &accounts[key];
+            let mut value =
+                decode_account(key, &state.account.data, state.slot, StateSource::Enrichment)?;
+            MINT_INDEX.set_state_mint(key, &mut value);
+            Some(value)
+        })
+        .collect()
+}
+/// True when the indexed program is one of the account keys, including loaded addresses
+pub fn involves_program(tran: &TransactionWithStatusMeta) -> bool {
+    decode::involves_program(&Indexer, tran, INDEXER_CONFIG.start_block)
+}
@@ -0,0 +1,32 @@
+use indexer_logic::config::IndexerConfig;
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+const MANIFEST: &str = r#"
+specVersion: 0.0.2
+dataSources:
+  - kind: solana
+    name: token-metadata-indexer
+    network: devnet
+    source:
+      address: metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s
+      start_block: 120000
+"#;
+
+mod config {
+    use super::*;
+
+    #[test]
+    fn read_source_from_manifest() {
+        let config = IndexerConfig::from_manifest(MANIFEST);
+        assert_eq!(
+            config.address.as_str(),
+            "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        );
+        assert_eq!(config.network.as_str(), "devnet");
+        assert_eq!(config.start_block, 120000);
+        let program_id = Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s").unwrap();
+        assert!(config.is_program(&program_id));
+        assert!(!config.is_program(&Pubkey::default()));
+    }
+}
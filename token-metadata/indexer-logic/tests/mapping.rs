@@ -0,0 +1,151 @@
+use indexer_common::context::Parsers;
+use indexer_common::enrichment::AccountStates;
+use indexer_logic::config::INDEXER_CONFIG;
+use indexer_logic::mapping::decode_block;
+use massbit_solana_sdk::smart_contract::InstructionParser;
+use massbit_solana_sdk::transport::TransportValue;
+use massbit_solana_sdk::types::SolanaBlock;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use solana_program::hash::Hash;
+use solana_program::instruction::CompiledInstruction;
+use solana_program::message::Message;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction;
+use solana_transaction_status::{ConfirmedBlock, TransactionWithStatusMeta};
+use std::collections::HashMap;
+
+const TRANSACTION_COUNT: usize = 40;
+
+/// Parser naming the instruction after its first data byte
+struct MockParser;
+
+impl InstructionParser for MockParser {
+    fn unpack_instruction(&self, input: &[u8]) -> Result<TransportValue, anyhow::Error> {
+        let name = match input.first() {
+            Some(0) => "SignMetadata",
+            _ => "PuffMetadata",
+        };
+        Ok(TransportValue::new(name))
+    }
+}
+
+/// Transactions calling the program twice, one in four does not call it
+fn block(program_id: Pubkey) -> SolanaBlock {
+    let transactions = (0..TRANSACTION_COUNT)
+        .map(|ind| {
+            let mut account_keys = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+            account_keys.push(match ind % 4 {
+                3 => Pubkey::new_unique(),
+                _ => program_id,
+            });
+            let instructions = (0..2)
+                .map(|inst_ind| {
+                    CompiledInstruction::new_from_raw_parts(2, vec![inst_ind as u8], vec![0, 1])
+                })
+                .collect();
+            TransactionWithStatusMeta {
+                transaction: Transaction {
+                    signatures: vec![Signature::new(&[ind as u8 + 1; 64])],
+                    message: Message::new_with_compiled_instructions(
+                        1,
+                        0,
+                        1,
+                        account_keys,
+                        Hash::default(),
+                        instructions,
+                    ),
+                },
+                meta: None,
+            }
+        })
+        .collect();
+    SolanaBlock {
+        version: String::from("0.1"),
+        timestamp: 1640995200,
+        block_number: 120000,
+        block: ConfirmedBlock {
+            previous_blockhash: Hash::default().to_string(),
+            blockhash: Hash::new_unique().to_string(),
+            parent_slot: 119999,
+            transactions,
+            rewards: vec![],
+            block_time: None,
+            block_height: None,
+        },
+    }
+}
+
+fn pool(workers: usize) -> ThreadPool {
+    ThreadPoolBuilder::new()
+        .num_threads(workers)
+        .build()
+        .unwrap()
+}
+
+/// Entities in the order they are written to the store
+fn written(parsers: &Parsers, block: &SolanaBlock, pool: &ThreadPool) -> Vec<TransportValue> {
+    decode_block(parsers, &AccountStates::default(), block, pool)
+        .into_iter()
+        .flat_map(|values| values.unwrap())
+        .collect()
+}
+
+fn assert_same_sequence(left: &[TransportValue], right: &[TransportValue]) {
+    assert_eq!(left.len(), right.len());
+    for (left, right) in left.iter().zip(right.iter()) {
+        assert_eq!(left.name, right.name);
+        assert_eq!(left.values, right.values);
+    }
+}
+
+mod mapping {
+    use super::*;
+
+    #[test]
+    fn parallel_decode_matches_serial_run() {
+        let program_id = INDEXER_CONFIG
+            .program_id
+            .expect("Invalid program address in the manifest");
+        let block = block(program_id);
+        let parser = MockParser;
+        let mut parsers = HashMap::new();
+        parsers.insert(program_id, &parser as &(dyn InstructionParser + Sync));
+        let parsers = Parsers::Shared(parsers);
+
+        let serial = written(&parsers, &block, &pool(1));
+        // Two instructions and the transaction for each transaction calling the program
+        assert_eq!(serial.len(), 3 * 30);
+        for workers in [2, 4, 8] {
+            assert_same_sequence(&serial, &written(&parsers, &block, &pool(workers)));
+        }
+    }
+
+    #[test]
+    fn exclusive_parser_decodes_serially() {
+        let program_id = INDEXER_CONFIG
+            .program_id
+            .expect("Invalid program address in the manifest");
+        let block = block(program_id);
+        let parser = MockParser;
+        let mut shared = HashMap::new();
+        shared.insert(program_id, &parser as &(dyn InstructionParser + Sync));
+        let mut exclusive = HashMap::new();
+        exclusive.insert(program_id, &parser as &dyn InstructionParser);
+
+        let serial = written(&Parsers::Shared(shared), &block, &pool(1));
+        let exclusive = written(&Parsers::Exclusive(exclusive), &block, &pool(4));
+        assert_same_sequence(&serial, &exclusive);
+    }
+
+    #[test]
+    fn skip_programs_without_parser() {
+        let program_id = INDEXER_CONFIG
+            .program_id
+            .expect("Invalid program address in the manifest");
+        let block = block(program_id);
+        let parsers = Parsers::Shared(HashMap::new());
+        // Neither the instructions nor their transactions are saved, the block does not fail
+        assert!(written(&parsers, &block, &pool(1)).is_empty());
+    }
+}
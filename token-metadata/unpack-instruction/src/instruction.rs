@@ -1,5 +1,6 @@
 use borsh::BorshDeserialize;
 use mpl_token_metadata::instruction::MetadataInstruction;
+use mpl_token_metadata::state::{Collection, Creator, DataV2, Uses};
 use std::error::Error;
 use transport::interface::InstructionParser as InstructionParserTrait;
 use transport::{TransportValue, Value};
@@ -82,7 +83,14 @@ impl InstructionParser {
         input: mpl_token_metadata::instruction::CreateMetadataAccountArgs,
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("CreateMetadataAccount");
-        transport_value.set_value("data", Value::Null);
+        set_data_values(
+            &mut transport_value,
+            &input.data.name,
+            &input.data.symbol,
+            &input.data.uri,
+            input.data.seller_fee_basis_points,
+            &input.data.creators,
+        );
         transport_value.set_value("is_mutable", Value::from(input.is_mutable));
         Ok(transport_value)
     }
@@ -91,11 +99,20 @@ impl InstructionParser {
         input: mpl_token_metadata::instruction::UpdateMetadataAccountArgs,
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("UpdateMetadataAccount");
-        transport_value.set_value(
-            "data",
-            Value::from(input.data.map(|item| format!("{:?}", item))),
+        if let Some(data) = &input.data {
+            set_data_values(
+                &mut transport_value,
+                &data.name,
+                &data.symbol,
+                &data.uri,
+                data.seller_fee_basis_points,
+                &data.creators,
+            );
+        }
+        set_update_authority_values(
+            &mut transport_value,
+            input.update_authority.map(|key| key.to_string()),
         );
-        transport_value.set_value("update_authority", Value::Null);
         transport_value.set_value(
             "primary_sale_happened",
             Value::from(input.primary_sale_happened),
@@ -129,13 +146,33 @@ impl InstructionParser {
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("DeprecatedSetReservationList");
         transport_value.set_value(
-            "reservations",
-            Value::from(
+            "reservation_addresses",
+            Value::List(
+                input
+                    .reservations
+                    .iter()
+                    .map(|item| Value::from(item.address.to_string()))
+                    .collect(),
+            ),
+        );
+        transport_value.set_value(
+            "reservation_spots_remaining",
+            Value::List(
                 input
                     .reservations
                     .iter()
-                    .map(|item| format!("{:?}", item))
-                    .collect::<Vec<String>>(),
+                    .map(|item| Value::from(item.spots_remaining))
+                    .collect(),
+            ),
+        );
+        transport_value.set_value(
+            "reservation_total_spots",
+            Value::List(
+                input
+                    .reservations
+                    .iter()
+                    .map(|item| Value::from(item.total_spots))
+                    .collect(),
             ),
         );
         transport_value.set_value(
@@ -208,11 +245,13 @@ impl InstructionParser {
         input: mpl_token_metadata::instruction::UpdateMetadataAccountArgsV2,
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("UpdateMetadataAccountV2");
-        transport_value.set_value(
-            "data",
-            Value::from(input.data.map(|item| format!("{:?}", item))),
+        if let Some(data) = &input.data {
+            set_data_v2_values(&mut transport_value, data);
+        }
+        set_update_authority_values(
+            &mut transport_value,
+            input.update_authority.map(|key| key.to_string()),
         );
-        transport_value.set_value("update_authority", Value::Null);
         transport_value.set_value(
             "primary_sale_happened",
             Value::from(input.primary_sale_happened),
@@ -225,7 +264,7 @@ impl InstructionParser {
         input: mpl_token_metadata::instruction::CreateMetadataAccountArgsV2,
     ) -> Result<TransportValue, anyhow::Error> {
         let mut transport_value = TransportValue::new("CreateMetadataAccountV2");
-        transport_value.set_value("data", Value::Null);
+        set_data_v2_values(&mut transport_value, &input.data);
         transport_value.set_value("is_mutable", Value::from(input.is_mutable));
         Ok(transport_value)
     }
@@ -274,3 +313,108 @@ impl InstructionParser {
         Ok(transport_value)
     }
 }
+/// Set the common fields of `Data`/`DataV2` as separate values.
+/// Creators are stored as three parallel lists, one item per creator.
+fn set_data_values(
+    transport_value: &mut TransportValue,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    seller_fee_basis_points: u16,
+    creators: &Option<Vec<Creator>>,
+) {
+    transport_value.set_value("name", Value::from(trim_padding(name)));
+    transport_value.set_value("symbol", Value::from(trim_padding(symbol)));
+    transport_value.set_value("uri", Value::from(trim_padding(uri)));
+    transport_value.set_value(
+        "seller_fee_basis_points",
+        Value::from(seller_fee_basis_points),
+    );
+    let creators = creators
+        .as_ref()
+        .map(|items| items.as_slice())
+        .unwrap_or(&[]);
+    transport_value.set_value(
+        "creator_addresses",
+        Value::List(
+            creators
+                .iter()
+                .map(|creator| Value::from(creator.address.to_string()))
+                .collect(),
+        ),
+    );
+    transport_value.set_value(
+        "creator_verified",
+        Value::List(
+            creators
+                .iter()
+                .map(|creator| Value::from(creator.verified))
+                .collect(),
+        ),
+    );
+    transport_value.set_value(
+        "creator_shares",
+        Value::List(
+            creators
+                .iter()
+                .map(|creator| Value::from(creator.share))
+                .collect(),
+        ),
+    );
+}
+fn set_data_v2_values(transport_value: &mut TransportValue, data: &DataV2) {
+    set_data_values(
+        transport_value,
+        &data.name,
+        &data.symbol,
+        &data.uri,
+        data.seller_fee_basis_points,
+        &data.creators,
+    );
+    set_collection_values(transport_value, &data.collection);
+    set_uses_values(transport_value, &data.uses);
+}
+fn set_collection_values(transport_value: &mut TransportValue, collection: &Option<Collection>) {
+    transport_value.set_value(
+        "collection_key",
+        Value::from(collection.as_ref().map(|item| item.key.to_string())),
+    );
+    transport_value.set_value(
+        "collection_verified",
+        Value::from(collection.as_ref().map(|item| item.verified)),
+    );
+}
+fn set_uses_values(transport_value: &mut TransportValue, uses: &Option<Uses>) {
+    transport_value.set_value(
+        "use_method",
+        match uses.as_ref().map(|item| &item.use_method) {
+            Some(mpl_token_metadata::state::UseMethod::Burn) => Value::from("Burn"),
+            Some(mpl_token_metadata::state::UseMethod::Multiple) => Value::from("Multiple"),
+            Some(mpl_token_metadata::state::UseMethod::Single) => Value::from("Single"),
+            None => Value::Null,
+        },
+    );
+    transport_value.set_value(
+        "uses_remaining",
+        Value::from(uses.as_ref().map(|item| item.remaining)),
+    );
+    transport_value.set_value(
+        "uses_total",
+        Value::from(uses.as_ref().map(|item| item.total)),
+    );
+}
+/// The new update authority is stored in base58, `None` means the authority is unchanged
+fn set_update_authority_values(
+    transport_value: &mut TransportValue,
+    update_authority: Option<String>,
+) {
+    transport_value.set_value(
+        "update_authority_changed",
+        Value::from(update_authority.is_some()),
+    );
+    transport_value.set_value("update_authority", Value::from(update_authority));
+}
+/// Metadata strings are zero padded to a fixed length on chain
+fn trim_padding(value: &str) -> String {
+    value.trim_end_matches(char::from(0)).to_string()
+}
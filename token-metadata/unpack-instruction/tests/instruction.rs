@@ -0,0 +1,163 @@
+use borsh::BorshSerialize;
+use mpl_token_metadata::instruction::{
+    CreateMetadataAccountArgsV2, MetadataInstruction, UpdateMetadataAccountArgsV2,
+};
+use mpl_token_metadata::state::{Collection, Creator, DataV2, UseMethod, Uses};
+use solana_program::pubkey::Pubkey;
+use transport::interface::InstructionParser as InstructionParserTrait;
+use transport::{TransportValue, Value};
+use unpack_instruction::instruction::InstructionParser;
+
+/// Serialize an instruction like a client and unpack it with the parser
+fn unpack(instruction: MetadataInstruction) -> TransportValue {
+    let data = instruction.try_to_vec().unwrap();
+    InstructionParser.unpack_instruction(&data).unwrap()
+}
+
+/// Metadata data with the name padded with zeros like the on-chain strings
+fn data(
+    creators: Option<Vec<Creator>>,
+    collection: Option<Collection>,
+    uses: Option<Uses>,
+) -> DataV2 {
+    DataV2 {
+        name: format!("{:\0<32}", "Degen #1"),
+        symbol: String::from("DGN"),
+        uri: String::from("https://arweave.net/1.json"),
+        seller_fee_basis_points: 500,
+        creators,
+        collection,
+        uses,
+    }
+}
+
+fn get(value: &TransportValue, field: &str) -> Option<Value> {
+    value.values.get(field).cloned()
+}
+
+mod instruction {
+    use super::*;
+
+    #[test]
+    fn unpack_data_of_create_metadata() {
+        let (first, second, collection) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let creators = vec![
+            Creator {
+                address: first,
+                verified: true,
+                share: 60,
+            },
+            Creator {
+                address: second,
+                verified: false,
+                share: 40,
+            },
+        ];
+        let args = CreateMetadataAccountArgsV2 {
+            data: data(
+                Some(creators),
+                Some(Collection {
+                    verified: false,
+                    key: collection,
+                }),
+                Some(Uses {
+                    use_method: UseMethod::Multiple,
+                    remaining: 3,
+                    total: 5,
+                }),
+            ),
+            is_mutable: true,
+        };
+        let value = unpack(MetadataInstruction::CreateMetadataAccountV2(args));
+        assert_eq!(value.name, "CreateMetadataAccountV2");
+        assert_eq!(get(&value, "name"), Some(Value::from("Degen #1")));
+        assert_eq!(get(&value, "symbol"), Some(Value::from("DGN")));
+        assert_eq!(
+            get(&value, "seller_fee_basis_points"),
+            Some(Value::from(500_u16))
+        );
+        assert_eq!(
+            get(&value, "creator_addresses"),
+            Some(Value::List(vec![
+                Value::from(first.to_string()),
+                Value::from(second.to_string())
+            ]))
+        );
+        assert_eq!(
+            get(&value, "creator_verified"),
+            Some(Value::List(vec![Value::from(true), Value::from(false)]))
+        );
+        assert_eq!(
+            get(&value, "creator_shares"),
+            Some(Value::List(vec![Value::from(60_u8), Value::from(40_u8)]))
+        );
+        assert_eq!(
+            get(&value, "collection_key"),
+            Some(Value::from(Some(collection.to_string())))
+        );
+        assert_eq!(
+            get(&value, "collection_verified"),
+            Some(Value::from(Some(false)))
+        );
+        assert_eq!(get(&value, "use_method"), Some(Value::from("Multiple")));
+        assert_eq!(
+            get(&value, "uses_remaining"),
+            Some(Value::from(Some(3_u64)))
+        );
+        assert_eq!(get(&value, "uses_total"), Some(Value::from(Some(5_u64))));
+        assert_eq!(get(&value, "is_mutable"), Some(Value::from(true)));
+    }
+
+    #[test]
+    fn unpack_data_without_creators_collection_and_uses() {
+        let args = CreateMetadataAccountArgsV2 {
+            data: data(None, None, None),
+            is_mutable: false,
+        };
+        let value = unpack(MetadataInstruction::CreateMetadataAccountV2(args));
+        assert_eq!(get(&value, "creator_addresses"), Some(Value::List(vec![])));
+        assert_eq!(get(&value, "collection_key"), Some(Value::Null));
+        assert_eq!(get(&value, "collection_verified"), Some(Value::Null));
+        assert_eq!(get(&value, "use_method"), Some(Value::Null));
+        assert_eq!(get(&value, "uses_remaining"), Some(Value::Null));
+        assert_eq!(get(&value, "uses_total"), Some(Value::Null));
+    }
+
+    #[test]
+    fn unpack_update_without_data() {
+        let authority = Pubkey::new_unique();
+        let args = UpdateMetadataAccountArgsV2 {
+            data: None,
+            update_authority: Some(authority),
+            primary_sale_happened: Some(true),
+            is_mutable: None,
+        };
+        let value = unpack(MetadataInstruction::UpdateMetadataAccountV2(args));
+        assert_eq!(value.name, "UpdateMetadataAccountV2");
+        // Fields of the data are only set when the update carries it
+        assert_eq!(get(&value, "name"), None);
+        assert_eq!(get(&value, "creator_addresses"), None);
+        assert_eq!(
+            get(&value, "update_authority"),
+            Some(Value::from(Some(authority.to_string())))
+        );
+        assert_eq!(
+            get(&value, "update_authority_changed"),
+            Some(Value::from(true))
+        );
+        assert_eq!(
+            get(&value, "primary_sale_happened"),
+            Some(Value::from(Some(true)))
+        );
+        assert_eq!(get(&value, "is_mutable"), Some(Value::Null));
+    }
+
+    #[test]
+    fn reject_unknown_instruction() {
+        assert!(InstructionParser.unpack_instruction(&[255]).is_err());
+    }
+}